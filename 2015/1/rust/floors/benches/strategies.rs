@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use floors::Strategy;
+use rand::prelude::*;
+
+fn random_path(length: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(2015);
+    (0..length)
+        .map(|_| if rng.gen_bool(0.5) { '(' } else { ')' })
+        .collect()
+}
+
+fn strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("floor");
+    for length in [1_000, 100_000, 10_000_000] {
+        let path = random_path(length);
+        group.throughput(Throughput::Bytes(length as u64));
+        for strategy in Strategy::ALL {
+            group.bench_with_input(
+                BenchmarkId::new(strategy.name(), length),
+                path.as_str(),
+                |b, path| b.iter(|| strategy.floor(black_box(path))),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, strategies);
+criterion_main!(benches);
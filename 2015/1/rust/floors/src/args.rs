@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use floors::Strategy;
+
+#[derive(Debug, Parser)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// file holding the puzzle input.
+    pub input: PathBuf,
+
+    #[arg(long, value_enum, default_value_t = Strategy::Imperative)]
+    pub strategy: Strategy,
+
+    /// also report the position of the first instruction that reaches the basement.
+    #[arg(long)]
+    pub basement: bool,
+}
@@ -0,0 +1,115 @@
+use aoc_common::{Answer, Input, Solution};
+use rayon::prelude::*;
+
+mod strategy;
+mod walk;
+
+pub use strategy::Strategy;
+pub use walk::{par_walk, walk, walk_reader, ParseFloorError, Walk, Walker};
+
+#[derive(Debug)]
+pub enum Floor {
+    Up,
+    Down,
+}
+
+pub fn floors(s: &str) -> Vec<Floor> {
+    s.chars()
+        .map(|char| match char {
+            '(' => Floor::Up,
+            ')' => Floor::Down,
+            _ => panic!("invalid char"),
+        })
+        .collect()
+}
+
+pub fn par_floors(s: &str) -> Vec<Floor> {
+    const CHUNK_SIZE: usize = 1_000;
+    let chunks = s.as_bytes().par_chunks(CHUNK_SIZE);
+
+    chunks
+        .into_par_iter()
+        .map(|chunk| {
+            chunk
+                .iter()
+                .map(|&byte| match byte {
+                    b'(' => Floor::Up,
+                    b')' => Floor::Down,
+                    _ => panic!("invalid byte"),
+                })
+                .collect::<Vec<Floor>>()
+        })
+        .flatten()
+        .collect::<Vec<Floor>>()
+}
+
+pub fn floor_imperative(fs: &[Floor]) -> i32 {
+    let mut floor: i32 = 0;
+    for f in fs.iter() {
+        match f {
+            Floor::Up => floor += 1,
+            Floor::Down => floor -= 1,
+        }
+    }
+    floor
+}
+
+pub fn floor_map(fs: &[Floor]) -> i32 {
+    fs.iter()
+        .map(|char| match char {
+            Floor::Up => 1,
+            Floor::Down => -1,
+        })
+        .sum()
+}
+
+pub fn floor_fold(fs: &[Floor]) -> i32 {
+    fs.iter().fold(0, |mut acc, char| {
+        match char {
+            Floor::Up => acc += 1,
+            Floor::Down => acc -= 1,
+        }
+        acc
+    })
+}
+
+pub fn par_floor(s: &str) -> i32 {
+    par_floors(s)
+        .par_iter()
+        .map(|floor| match floor {
+            Floor::Up => 1,
+            Floor::Down => -1,
+        })
+        .sum()
+}
+
+pub fn basement_imperative(fs: &[Floor]) -> Option<usize> {
+    let mut floor: i32 = 0;
+    for (i, c) in fs.iter().enumerate() {
+        match c {
+            Floor::Up => floor += 1,
+            Floor::Down => floor -= 1,
+        }
+        if floor < 0 {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const INPUT: Option<&'static str> = Some(include_str!("../input"));
+
+    fn part_one(input: &Input) -> Answer {
+        let walk = walk(input.read_to_string()?.as_bytes())?;
+        Ok(walk.floor.to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let walk = walk(input.read_to_string()?.as_bytes())?;
+        let position = walk.basement.ok_or("Santa never enters the basement")?;
+        Ok(position.to_string())
+    }
+}
@@ -1,122 +1,40 @@
-use rand::distributions::Uniform;
-use rand::prelude::*;
-use rayon::prelude::*;
+mod args;
 
-#[derive(Debug)]
-enum Floor {
-    Up,
-    Down,
-}
-
-fn floors(s: &str) -> Vec<Floor> {
-    s.chars()
-        .map(|char| match char {
-            '(' => Floor::Up,
-            ')' => Floor::Down,
-            _ => panic!("invalid char"),
-        })
-        .collect()
-}
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::time::Instant;
 
-fn par_floors(s: &str) -> Vec<Floor> {
-    const CHUNK_SIZE: usize = 1_000;
-    let chunks = s.as_bytes().par_chunks(CHUNK_SIZE);
+use args::Args;
+use clap::Parser;
+use floors::{walk, walk_reader, Strategy};
 
-    chunks
-        .into_par_iter()
-        .map(|chunk| {
-            chunk
-                .iter()
-                .map(|&byte| match byte {
-                    b'(' => Floor::Up,
-                    b')' => Floor::Down,
-                    _ => panic!("invalid byte"),
-                })
-                .collect::<Vec<Floor>>()
-        })
-        .flatten()
-        .collect::<Vec<Floor>>()
-}
+fn main() -> io::Result<()> {
+    let args = Args::parse();
 
-fn floor_imperative(fs: &Vec<Floor>) -> i32 {
-    let mut floor: i32 = 0;
-    for f in fs.iter() {
-        match f {
-            Floor::Up => floor += 1,
-            Floor::Down => floor -= 1,
+    let start = Instant::now();
+    let (floor, basement) = match args.strategy {
+        // no need to hold the input in memory for this one
+        Strategy::Walk => {
+            let walk = walk_reader(BufReader::new(File::open(&args.input)?))?;
+            (walk.floor, walk.basement)
         }
-    }
-    floor
-}
-
-fn floor_map(fs: &Vec<Floor>) -> i32 {
-    fs.iter()
-        .map(|char| match char {
-            Floor::Up => 1,
-            Floor::Down => -1,
-        })
-        .sum()
-}
-
-fn floor_fold(fs: &Vec<Floor>) -> i32 {
-    fs.iter().fold(0, |mut acc, char| {
-        match char {
-            Floor::Up => acc += 1,
-            Floor::Down => acc -= 1,
+        strategy => {
+            let path = fs::read_to_string(&args.input)?;
+            let path = path.trim();
+            let basement = if args.basement {
+                walk(path.as_bytes())?.basement
+            } else {
+                None
+            };
+            (strategy.floor(path), basement)
         }
-        acc
-    })
-}
-
-fn par_floor(s: &str) -> i32 {
-    par_floors(s)
-        .par_iter()
-        .map(|floor| match floor {
-            Floor::Up => 1,
-            Floor::Down => -1,
-        })
-        .sum()
-}
+    };
+    let duration = start.elapsed();
 
-fn basement_imperative(fs: &Vec<Floor>) -> Option<usize> {
-    let mut floor: i32 = 0;
-    for (i, c) in fs.iter().enumerate() {
-        match c {
-            Floor::Up => floor += 1,
-            Floor::Down => floor -= 1,
-        }
-        if floor < 0 {
-            return Some(i + 1);
-        }
+    println!("floor = {}", floor);
+    if args.basement {
+        println!("basement = {:?}", basement);
     }
-    return None;
-}
-
-fn random_path(length: usize) -> String {
-    let rng = thread_rng();
-
-    rng.sample_iter(Uniform::new_inclusive(0, 1))
-        .take(length)
-        .map(|bit| if bit == 0 { ')' } else { '(' })
-        .collect()
-}
-
-fn main() {
-    let line = "()(((()))(()()()((((()(((())(()(()((((((()(()(((())))((()(((()))((())(()((()()()()(((())(((((((())))()()(()(()(())(((((()()()((())(((((()()))))()(())(((())(())((((((())())))(()())))()))))()())()())((()()((()()()()(()((((((((()()())((()()(((((()(((())((())(()))()((((()((((((((())()((()())(())((()))())((((()())(((((((((((()()(((((()(()))())(((()(()))())((()(()())())())(()(((())(())())()()(()(()((()))((()))))((((()(((()))))((((()(()(()())())()(((()((((())((((()(((()()(())()()()())((()((((((()((()()))()((()))()(()()((())))(((()(((()))((()((()(()))(((()()(()(()()()))))()()(((()(((())())))))((()(((())()(()(())((()())))((((())))(()(()(()())()((()())))(((()((()(())()()((()((())(()()((())(())()))()))((()(())()))())(((((((()(()()(()(())())))))))(()((((((())((((())((())())(()()))))()(())(()())()())((())(()))))(()))(()((()))()(()((((((()()()()((((((((()(()(())((()()(()()))(())()())()((())))()))()())(((()))(())()(())()))()((()((()(()()())(())()()()((())())))((()()(()()((()(())()()())(((()(()()))))(())))(()(()())()))()()))))))()))))((((((())))())))(()(())())(()())))))(()))()))))))()((()))))()))))(()(()((()())())(()()))))(((())()))())())())(((()(()()))(())()(())(())((((((()()))))((()(()))))))(()))())(((()()(()))()())()()()())))))))))))))(())(()))(()))((()(())(()())(())())(()())(())()()(()())))()()()))(())())()))())())(())((())))))))(())))(())))))()))))((())(()(((()))))(()))()((()(())))(()())(((((()))()())()()))))()))))()))())(()(()()()))()))))))((()))))))))))()((()))((()(())((())()()(()()))()(()))))()()(()))()))(((())))(())()((())(())(()())()())())))))))())))()((())))()))(()))()()))(((((((()))())(()()))(()()(()))()(()((()())()))))))(((()()()())))(())()))()())(()()))()()))))))))(())))()))()()))))))()))()())))()(())(())))))()(())()()(()()))))())((()))))()))))(()(((((()))))))))())))())()(())()()))))(())))())()()())()()())()(()))))()))()))))))))())))((()))()))()))())))()())()()())))())))(()((())()((()))())))))())()(())((())))))))))))())()())(())())())(()))(()))()))())(()(())())()())()()(()))))(()(())))))))(())))())(())))))))())()()(())())())))(())))))()))()(()())()(()))())())))))()()(()))()))))())))))))))()))))()))))))())()())()()))))()())))())))))))))))()()))))()()(((()))()()(())()))))((()))))(()))(())())))(())()))))))(()))()))))(())())))))()))(()())))))))))))))())))))))))()((()())(()())))))))((()))))(())(())))()(()())())))())())(()()()())))()))))))())))))())()()())))))))))))()()(()))))()())()))((()())(()))))()(()))))))))))()())())(((())(()))))())()))()))()))))))()))))))(()))))()))))()(())))(())))(()))())()()(()()))()))(()()))))))))()))(()))())(()()(()(()())()()))()))))))))(())))))((()()(()))())())))))()))())(()())()()))())))()(()()()()))((())())))())()(()()))()))))))))(()))(())))()))))(()(()())(()))))()())())()))()()))())))))))))))())()))))))()))))))))())))))()))))())(()())))(())()))())())))))()()(()()())(()())))()()))(((()))(()()()))))()))))()))))((())))()((((((()()))))))())))))))))))(((()))))))))))))(())())))))())(()))))))(()))((()))())))()(()((()))()))()))))))))))())()))()(()()))))())))())(())()(()))()))())(()))()))))(()()))()()(())))))()))(())(()(()()))(()()())))))(((()))))))()))))))))))))(())(()))))()())())()()((()()))())))))(()))))())))))))()()()))))))))())))()(((()()))(())))))(((())())))))((()))()(()))(()))))(()())))(()))())))))()))))(())(())))()((()))(())())))()()))()))))))))()))(()()()(()()()(()))())(())()())(((()))(())))))))))(((()())))()()))))))))()(())(()))()((((())(())(()())))()))(((())()()()))((()))(()))())())))())))(()))())()())())(()(())())()()()(())))())(())))(())))(())()))()))(()((()))))))))())(()))))))())(()()))()()))()(()(()())))()()(()((()((((((()))(())))()()()))())()))((()()(()))())((()(()(()))(()()))))()())))()))()())))))))()()((()())(())))()))(()))(())(()))())(()(())))()()))))))(((()(((()()))()(()(())())((()()))()))()))()))()(()()()(()))((()())()(())))()()))(((())()()())(())()((()()()()(()(())(()()))()(((((()())))((())))))(()()()))))(((()(())))()))((()((()(())()(()((())))((()())()(()))(((()())()()(()))(())(((()((()())()((())()())(((()()))((()((())(()))(()())(()()()))((()))(())(()((()()())((()))(())))(())(())(())))(()())))(((((()(()(((((()())((((()(()())(())(()()(((())((()(((()()(((()()((((((())))())(()((((((()(()))()))()()((()((()))))()(()()(()((()()))))))(((((()(((((())()()()(())())))))))()))((()()(())))(())(()()()())))))(()((((())))))))()()(((()(()(()(()(()())()()()(((((((((()()())()(()))((()()()()()(((((((()())()((())()))((((((()(()(()(()())(((()(((((((()(((())(((((((((())(())())()))((()(()))(((()()())(())(()(()()(((()(())()))())))(())((((((())(()()())()()(((()(((())(()(((())(((((((()(((((((((()))(())(()(()(()))))((()))()(())())())((()(()((()()))((()()((()(())(())(()((())(((())(((()()()((((((()()(())((((())()))))(())((()(()((())))(((((()(()()())())((())())))((())((()((()()((((((())(((()()(()())())(()(()))(()(()))())())()(((((((()(((()(())()()((())((()(()()((()(()()(((((((((((())((())((((((())((()((((()(()((((()(((((((())()((()))))())()((()((((()(()(((()((()())))(())())(((()(((())((((((()(((((((((()()(())))(()(((((()((((()())))((()((()((()(()()(((())((((((((((((()(((())(()(((((()))(()()(()()()()()()((())(((((((())(((((())))))())()(()()(()(()(((()()(((((())(()((()((()(((()()((()((((())()))()((((())(())))()())(((())(())(()()((()(((()()((((((((((()()(()())())(((((((((())((((()))()()((((())(()((((()(((())())(((((((((((()((((())))(())(()(((()(((()((())(((((()((()()(()(()()((((((()((((()((()(()((()(()((((((()))))()()(((((()((()(()(())()))(())(((((((()((((()())(()((()((()(()))())))(())((()))))(((((((()()()())(()))(()()((()())()((()((()()()(()(()()))(()())(())(((((()(((((((((((()((()(((()(((((((()()((((((()(((((()(()((()(((((())((((((()))((((())((()()((())(((())()(((((()()(((((()((()(()(((((((()(((((()((()((()((())(())((())(()))()()))(()()(()(()()(((((((()(((()(((())()(((((()((((((()())((((())()((()((()(()()())(()))((((()()((((((()((()(()(()((((()((()((())((((((()(()(())((((((()((((((((((()((())()))()(()(()(((((()()()))((())))()(()((((((((((((((()(((()((((()((())((()((()(((()()(()(((()((())(()()())))()(()(()(((((()()(()(()((((()(((((())()(()(()))(((((()()(((()()(())((((((((((((((())((())(((((((((((())()()()(())()(()(()(((((((((())(((()))(()()())(()((((()(())(((((()())(())((((((((())()((((()((((((())(()((()(())(((()((((()))(((((((((()()))((((()(())()()()(())(()((())((()()))()(((())(((((())((((((()()))(((((((((()((((((())))(((((((()((()(()(())))())(()(()))()(((((()())(()))()(()(())(((()))))())()())))(((((()))())()((()(()))))((()()()((((((()))()()((((((((())((()(()(((()(()((())((()())(()((((())(()(((()()()(()(()()))())())((((((((((())())((()))()((())(())(())))())()(()()(())))())(()))(((()(()()(((()(((())))()(((()(())()((((((())()))()))()((((((()(()(((((()())))()))))())()()(((()(((((())((()()(()((()((()(()(()(())))(()()()()((()(())(((()((()))((((()))())(())))())(()))()()()())()))(((()()())()((())))(())(()()()()(()())((()(()()((((())))((()((()(())((()(()((())()(()()(((()())()()())((()))((())(((()()(())))()()))(((()((())()(((((()())(())((())()())())((((((()(()(((((()))(()(";
-    let fs = floors(line);
-    println!("{}", floor_imperative(&fs));
-    println!("{}", floor_fold(&fs));
-    println!("{}", floor_map(&fs));
-    println!("{:?}", basement_imperative(&fs));
-
-    println!("{:?}", par_floors("(()))"));
-    println!("{:?}", floors("(()))"));
-
-    println!("{:?}", par_floor(line));
-
-    let long_line = random_path(1_000_000_000);
-    let fs = floors(&long_line);
-    println!("{}", floor_imperative(&fs));
-
-    // println!("{}", par_floor(&long_line));
+    println!("Elapsed time = {:?}", duration);
+    Ok(())
 }
@@ -0,0 +1,77 @@
+use clap::{builder::PossibleValue, ValueEnum};
+
+use crate::{floor_fold, floor_imperative, floor_map, floors, par_floor, par_walk, walk};
+
+/// The different ways of working out the floor Santa ends up on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    Imperative,
+    Map,
+    Fold,
+    Parallel,
+    Walk,
+    ParallelWalk,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 6] = [
+        Strategy::Imperative,
+        Strategy::Map,
+        Strategy::Fold,
+        Strategy::Parallel,
+        Strategy::Walk,
+        Strategy::ParallelWalk,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Imperative => "imperative",
+            Strategy::Map => "map",
+            Strategy::Fold => "fold",
+            Strategy::Parallel => "parallel",
+            Strategy::Walk => "walk",
+            Strategy::ParallelWalk => "parallel-walk",
+        }
+    }
+
+    /// Final floor after following `path`.
+    ///
+    /// Panics if `path` holds anything other than parentheses.
+    ///
+    /// # Example
+    /// ```rust
+    /// use floors::Strategy;
+    ///
+    /// for strategy in Strategy::ALL {
+    ///     assert_eq!(strategy.floor("))((((("), 3);
+    /// }
+    /// ```
+    pub fn floor(&self, path: &str) -> i64 {
+        match self {
+            Strategy::Imperative => floor_imperative(&floors(path)) as i64,
+            Strategy::Map => floor_map(&floors(path)) as i64,
+            Strategy::Fold => floor_fold(&floors(path)) as i64,
+            Strategy::Parallel => par_floor(path) as i64,
+            Strategy::Walk => walk(path.as_bytes()).expect("invalid path").floor,
+            Strategy::ParallelWalk => par_walk(path.as_bytes()).expect("invalid path").floor,
+        }
+    }
+}
+
+impl ValueEnum for Strategy {
+    fn value_variants<'a>() -> &'a [Self] {
+        &Self::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let help = match self {
+            Self::Imperative => "loop over the instructions",
+            Self::Map => "map the instructions to steps and sum them",
+            Self::Fold => "fold over the instructions",
+            Self::Parallel => "sum the steps with rayon",
+            Self::Walk => "stream through the input in a single pass",
+            Self::ParallelWalk => "walk chunks of the input in parallel",
+        };
+        Some(PossibleValue::new(self.name()).help(help))
+    }
+}
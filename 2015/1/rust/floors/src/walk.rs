@@ -0,0 +1,238 @@
+use std::fmt;
+use std::io::{self, Read};
+
+use rayon::prelude::*;
+
+/// Where a walk through the instructions ends up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Walk {
+    pub floor: i64,
+    /// 1-based position of the instruction that first takes Santa to the basement.
+    pub basement: Option<usize>,
+}
+
+/// A byte that is neither a parenthesis nor whitespace.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseFloorError {
+    pub position: usize,
+    pub byte: u8,
+}
+
+impl fmt::Display for ParseFloorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid byte {:?} at position {}",
+            self.byte as char, self.position
+        )
+    }
+}
+
+impl std::error::Error for ParseFloorError {}
+
+impl From<ParseFloorError> for io::Error {
+    fn from(e: ParseFloorError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, e)
+    }
+}
+
+fn step(byte: u8, position: usize) -> Result<i64, ParseFloorError> {
+    match byte {
+        b'(' => Ok(1),
+        b')' => Ok(-1),
+        b if b.is_ascii_whitespace() => Ok(0),
+        byte => Err(ParseFloorError { position, byte }),
+    }
+}
+
+/// Keeps track of a walk that is fed its instructions a few bytes at a time.
+///
+/// Positions count every byte fed so far, whitespace included, so they line up with the
+/// positions in the input.
+#[derive(Debug, Default)]
+pub struct Walker {
+    walk: Walk,
+    position: usize,
+}
+
+impl Walker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<(), ParseFloorError> {
+        for &byte in bytes {
+            self.position += 1;
+            self.walk.floor += step(byte, self.position)?;
+            if self.walk.floor < 0 && self.walk.basement.is_none() {
+                self.walk.basement = Some(self.position);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> Walk {
+        self.walk
+    }
+}
+
+/// Walks the instructions in a single pass.
+///
+/// # Example
+/// ```rust
+/// use floors::{walk, Walk};
+///
+/// assert_eq!(walk(b"(()))").unwrap(), Walk { floor: -1, basement: Some(5) });
+/// assert_eq!(walk(b"(())\n").unwrap(), Walk { floor: 0, basement: None });
+/// assert!(walk(b"(x)").is_err());
+/// ```
+pub fn walk(bytes: &[u8]) -> Result<Walk, ParseFloorError> {
+    let mut walker = Walker::new();
+    walker.feed(bytes)?;
+    Ok(walker.finish())
+}
+
+/// Walks the instructions as they are read, through a fixed size buffer.
+pub fn walk_reader(mut reader: impl Read) -> io::Result<Walk> {
+    let mut buffer = [0u8; 8 * 1024];
+    let mut walker = Walker::new();
+    loop {
+        let n = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(walker.finish()),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        walker.feed(&buffer[..n])?;
+    }
+}
+
+const CHUNK_SIZE: usize = 1 << 16;
+
+/// Net change in floor over a chunk and the lowest floor reached inside it, both relative to
+/// the floor the chunk starts on.
+#[derive(Clone, Copy, Debug)]
+struct Summary {
+    delta: i64,
+    lowest: i64,
+}
+
+fn summarize(chunk: &[u8], offset: usize) -> Result<Summary, ParseFloorError> {
+    let mut summary = Summary {
+        delta: 0,
+        lowest: 0,
+    };
+    for (i, &byte) in chunk.iter().enumerate() {
+        summary.delta += step(byte, offset + i + 1)?;
+        summary.lowest = summary.lowest.min(summary.delta);
+    }
+    Ok(summary)
+}
+
+/// Parallel version of [`walk`].
+///
+/// Every chunk is summarized on its own, then a prefix sum over the summaries finds the first
+/// chunk that dips below the ground floor. Only that chunk needs to be walked again to find the
+/// exact position.
+///
+/// # Example
+/// ```rust
+/// use floors::{par_walk, walk};
+///
+/// let path = "()(".repeat(100_000) + &")".repeat(100_001);
+/// assert_eq!(par_walk(path.as_bytes()), walk(path.as_bytes()));
+/// ```
+pub fn par_walk(bytes: &[u8]) -> Result<Walk, ParseFloorError> {
+    par_walk_chunked(bytes, CHUNK_SIZE)
+}
+
+fn par_walk_chunked(bytes: &[u8], chunk_size: usize) -> Result<Walk, ParseFloorError> {
+    // collected in order first, so that the error reported is the one closest to the start
+    let summaries = bytes
+        .par_chunks(chunk_size)
+        .enumerate()
+        .map(|(i, chunk)| summarize(chunk, i * chunk_size))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect::<Result<Vec<Summary>, ParseFloorError>>()?;
+
+    let mut floor = 0;
+    let mut basement = None;
+    for (i, summary) in summaries.iter().enumerate() {
+        if basement.is_none() && floor + summary.lowest < 0 {
+            let offset = i * chunk_size;
+            let chunk = &bytes[offset..bytes.len().min(offset + chunk_size)];
+            let mut walker = Walker {
+                walk: Walk {
+                    floor,
+                    basement: None,
+                },
+                position: offset,
+            };
+            walker.feed(chunk)?;
+            basement = walker.finish().basement;
+        }
+        floor += summary.delta;
+    }
+
+    Ok(Walk { floor, basement })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    fn path(length: usize, seed: u64) -> Vec<u8> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..length)
+            .map(|_| if rng.gen_bool(0.5) { b'(' } else { b')' })
+            .collect()
+    }
+
+    #[test]
+    fn test_walk() {
+        assert_eq!(walk(b"").unwrap(), Walk::default());
+        assert_eq!(walk(b"(((").unwrap().floor, 3);
+        assert_eq!(walk(b"))(((((").unwrap().floor, 3);
+        assert_eq!(walk(b")())())").unwrap().floor, -3);
+        assert_eq!(walk(b")").unwrap().basement, Some(1));
+        assert_eq!(walk(b"()())").unwrap().basement, Some(5));
+        assert_eq!(
+            walk(b"(()x").unwrap_err(),
+            ParseFloorError {
+                position: 4,
+                byte: b'x'
+            }
+        );
+    }
+
+    #[test]
+    fn test_walk_reader() {
+        let bytes = path(100_000, 1);
+        let reader = io::BufReader::with_capacity(7, bytes.as_slice());
+        assert_eq!(walk_reader(reader).unwrap(), walk(&bytes).unwrap());
+
+        let err = walk_reader(&b"(a"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_par_walk() {
+        for seed in 0..20 {
+            let bytes = path(10_000, seed);
+            for chunk_size in [1, 3, 64, 1000, 20_000] {
+                assert_eq!(
+                    par_walk_chunked(&bytes, chunk_size).unwrap(),
+                    walk(&bytes).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_par_walk_reports_first_error() {
+        let err = par_walk_chunked(b"((x)y", 2).unwrap_err();
+        assert_eq!(err.position, 3);
+    }
+}
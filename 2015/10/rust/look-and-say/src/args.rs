@@ -0,0 +1,22 @@
+use clap::Parser;
+
+/// Says the seed out loud, again and again, and tells how long it gets
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// Digits to start from
+    #[arg(default_value = "1113122113")]
+    pub seed: String,
+
+    /// How many times to say it out loud
+    #[arg(default_value_t = 40)]
+    pub iterations: usize,
+
+    /// How many digits of the sequence to print
+    #[arg(long, default_value_t = 80)]
+    pub prefix: usize,
+
+    /// Also print how many of each element the sequence is made of
+    #[arg(long)]
+    pub elements: bool,
+}
@@ -0,0 +1,307 @@
+//! Sequences broken down into pieces that never interact again, after Conway's cosmological
+//! theorem, so that their length can be worked out without ever writing them down.
+
+use std::collections::HashMap;
+
+use crate::elements::{self, ELEMENTS};
+use crate::stream::said;
+
+/// Days [`split`] looks ahead. The way a sequence starts settles into a cycle well before.
+const HORIZON: usize = 24;
+
+/// Digits kept of a sequence when looking ahead, plenty to know how it starts.
+const PREFIX: usize = 64;
+
+/// Whether `right` never starts with `last`, whatever the day, so that the runs on either side
+/// of them never merge.
+fn stays_apart(last: u8, right: &[u8]) -> bool {
+    let mut prefix = right[..right.len().min(PREFIX)].to_vec();
+    let mut whole = prefix.len() == right.len();
+    for _ in 0..=HORIZON {
+        match prefix.first() {
+            Some(&first) if first != last => {}
+            _ => return false,
+        }
+        if !whole {
+            // the sequence may go on with the same digit
+            let run = prefix
+                .iter()
+                .rev()
+                .take_while(|&&d| d == prefix[prefix.len() - 1]);
+            prefix.truncate(prefix.len() - run.count());
+        }
+        prefix = said(prefix).collect();
+        if prefix.len() > PREFIX {
+            prefix.truncate(PREFIX);
+            whole = false;
+        }
+    }
+    true
+}
+
+/// Breaks `sequence` wherever the two sides will go on being said out loud independently,
+/// every day from now on.
+///
+/// # Example
+/// ```rust
+/// use look_and_say::chemistry::split;
+///
+/// // Ho, Pa, H, Ca and Co
+/// let pieces: Vec<&[u8]> = vec![b"1321132", b"13", b"22", b"12", b"32112"];
+/// assert_eq!(split(b"132113213221232112"), pieces);
+/// ```
+pub fn split(sequence: &[u8]) -> Vec<&[u8]> {
+    let mut pieces = vec![];
+    let mut start = 0;
+    for i in 1..sequence.len() {
+        if sequence[i - 1] != sequence[i] && stays_apart(sequence[i - 1], &sequence[i..]) {
+            pieces.push(&sequence[start..i]);
+            start = i;
+        }
+    }
+    if !sequence.is_empty() {
+        pieces.push(&sequence[start..]);
+    }
+    pieces
+}
+
+/// Pieces of sequences, known by id, and what each of them decays into the next day.
+///
+/// The 92 common elements come first, in order of atomic number. Any other piece, be it a
+/// transuranic element or a piece of a seed that has yet to settle, is added as it shows up.
+#[derive(Debug, Clone)]
+pub struct Chemistry {
+    pieces: Vec<Vec<u8>>,
+    ids: HashMap<Vec<u8>, usize>,
+    decays: Vec<Vec<usize>>,
+}
+
+impl Default for Chemistry {
+    fn default() -> Self {
+        let pieces: Vec<Vec<u8>> = ELEMENTS
+            .iter()
+            .map(|element| element.sequence.as_bytes().to_vec())
+            .collect();
+        let ids = pieces.iter().cloned().zip(0..).collect();
+        let decays = ELEMENTS
+            .iter()
+            .map(|element| {
+                let decay = element.decay.iter();
+                decay
+                    .map(|name| elements::position(name).unwrap())
+                    .collect()
+            })
+            .collect();
+        Chemistry {
+            pieces,
+            ids,
+            decays,
+        }
+    }
+}
+
+impl Chemistry {
+    pub fn new() -> Self {
+        Chemistry::default()
+    }
+
+    /// Number of pieces known so far.
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    pub fn piece(&self, id: usize) -> &[u8] {
+        &self.pieces[id]
+    }
+
+    /// Name of the piece, if it is a common element.
+    pub fn name(&self, id: usize) -> Option<&'static str> {
+        ELEMENTS.get(id).map(|element| element.name)
+    }
+
+    /// Ids of the pieces `id` decays into.
+    pub fn decay(&self, id: usize) -> &[usize] {
+        &self.decays[id]
+    }
+
+    /// Id of `piece`, and whether it is new.
+    fn insert(&mut self, piece: &[u8]) -> (usize, bool) {
+        if let Some(&id) = self.ids.get(piece) {
+            return (id, false);
+        }
+        let id = self.pieces.len();
+        self.pieces.push(piece.to_vec());
+        self.ids.insert(piece.to_vec(), id);
+        self.decays.push(vec![]);
+        (id, true)
+    }
+
+    /// Id of `piece`, adding it along with everything it will ever decay into.
+    fn add(&mut self, piece: &[u8]) -> usize {
+        let (id, new) = self.insert(piece);
+        let mut pending = if new { vec![id] } else { vec![] };
+        while let Some(id) = pending.pop() {
+            let next: Vec<u8> = said(self.pieces[id].iter().copied()).collect();
+            self.decays[id] = split(&next)
+                .into_iter()
+                .map(|piece| {
+                    let (product, new) = self.insert(piece);
+                    if new {
+                        pending.push(product);
+                    }
+                    product
+                })
+                .collect();
+        }
+        id
+    }
+
+    /// How many of each known piece `sequence` is made of, indexed by id.
+    pub fn compound(&mut self, sequence: &[u8]) -> Vec<u128> {
+        let ids: Vec<usize> = split(sequence)
+            .into_iter()
+            .map(|piece| self.add(piece))
+            .collect();
+        let mut counts = vec![0; self.len()];
+        for id in ids {
+            counts[id] += 1;
+        }
+        counts
+    }
+
+    /// How many of each piece there are `days` days after `counts`, raising the decay matrix
+    /// to the power of `days`. `None` if there are too many to count.
+    ///
+    /// # Panics
+    /// If `counts` has more pieces than are known.
+    pub fn after(&self, counts: &[u128], days: usize) -> Option<Vec<u128>> {
+        let mut counts = counts.to_vec();
+        counts.resize(self.len(), 0);
+        let mut power = Matrix::decay(self);
+        let mut days = days;
+        while days > 0 {
+            if days & 1 == 1 {
+                counts = power.apply(&counts)?;
+            }
+            days >>= 1;
+            if days > 0 {
+                power = power.square()?;
+            }
+        }
+        Some(counts)
+    }
+
+    /// Length of the sequence made of `counts` pieces.
+    pub fn length(&self, counts: &[u128]) -> Option<u128> {
+        counts
+            .iter()
+            .zip(&self.pieces)
+            .try_fold(0u128, |length, (&count, piece)| {
+                length.checked_add(count.checked_mul(piece.len() as u128)?)
+            })
+    }
+}
+
+/// Square matrix of counts, whose columns say how many of each piece the piece with that id
+/// decays into.
+struct Matrix {
+    size: usize,
+    cells: Vec<u128>,
+}
+
+impl Matrix {
+    fn decay(chemistry: &Chemistry) -> Self {
+        let size = chemistry.len();
+        let mut cells = vec![0; size * size];
+        for (id, decay) in chemistry.decays.iter().enumerate() {
+            for &product in decay {
+                cells[product * size + id] += 1;
+            }
+        }
+        Matrix { size, cells }
+    }
+
+    fn apply(&self, counts: &[u128]) -> Option<Vec<u128>> {
+        self.cells
+            .chunks(self.size)
+            .map(|row| {
+                row.iter()
+                    .zip(counts)
+                    .try_fold(0u128, |sum, (&a, &b)| sum.checked_add(a.checked_mul(b)?))
+            })
+            .collect()
+    }
+
+    fn square(&self) -> Option<Self> {
+        let n = self.size;
+        let mut cells = vec![0u128; n * n];
+        for i in 0..n {
+            for k in 0..n {
+                let a = self.cells[i * n + k];
+                if a == 0 {
+                    continue;
+                }
+                for j in 0..n {
+                    let product = a.checked_mul(self.cells[k * n + j])?;
+                    cells[i * n + j] = cells[i * n + j].checked_add(product)?;
+                }
+            }
+        }
+        Some(Matrix { size: n, cells })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stream::after;
+
+    #[test]
+    fn test_elements() {
+        let chemistry = Chemistry::new();
+        for (id, element) in ELEMENTS.iter().enumerate() {
+            let next: Vec<u8> = said(element.sequence.bytes()).collect();
+            let pieces: Vec<&[u8]> = chemistry
+                .decay(id)
+                .iter()
+                .map(|&product| chemistry.piece(product))
+                .collect();
+            assert_eq!(split(&next), pieces, "{} decays wrong", element.name);
+        }
+    }
+
+    #[test]
+    fn test_lengths() {
+        for seed in [
+            "1",
+            "3",
+            "22",
+            "1113122113",
+            "3333",
+            "123",
+            "4",
+            "1211145",
+            "",
+        ] {
+            let mut chemistry = Chemistry::new();
+            let counts = chemistry.compound(seed.as_bytes());
+            for days in 0..30 {
+                let length = chemistry.length(&chemistry.after(&counts, days).unwrap());
+                let expected = after(seed.as_bytes(), days).count() as u128;
+                assert_eq!(length, Some(expected), "{} after {} days", seed, days);
+            }
+        }
+    }
+
+    #[test]
+    fn test_too_long() {
+        let mut chemistry = Chemistry::new();
+        let counts = chemistry.compound(b"1");
+        assert!(chemistry.after(&counts, 200).is_some());
+        assert_eq!(chemistry.after(&counts, 1000), None);
+    }
+}
@@ -0,0 +1,480 @@
+//! Conway's 92 common elements: the sequences that every look-and-say sequence made of the
+//! digits 1 to 3 ends up splitting into, and what each one decays into the next day.
+
+/// A sequence that never interacts with its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Element {
+    pub name: &'static str,
+    pub sequence: &'static str,
+    /// Names of the elements it decays into, in order.
+    pub decay: &'static [&'static str],
+}
+
+/// Every common element, in order of atomic number.
+pub const ELEMENTS: [Element; 92] = [
+    Element {
+        name: "H",
+        sequence: "22",
+        decay: &["H"],
+    },
+    Element {
+        name: "He",
+        sequence: "13112221133211322112211213322112",
+        decay: &["Hf", "Pa", "H", "Ca", "Li"],
+    },
+    Element {
+        name: "Li",
+        sequence: "312211322212221121123222112",
+        decay: &["He"],
+    },
+    Element {
+        name: "Be",
+        sequence: "111312211312113221133211322112211213322112",
+        decay: &["Ge", "Ca", "Li"],
+    },
+    Element {
+        name: "B",
+        sequence: "1321132122211322212221121123222112",
+        decay: &["Be"],
+    },
+    Element {
+        name: "C",
+        sequence: "3113112211322112211213322112",
+        decay: &["B"],
+    },
+    Element {
+        name: "N",
+        sequence: "111312212221121123222112",
+        decay: &["C"],
+    },
+    Element {
+        name: "O",
+        sequence: "132112211213322112",
+        decay: &["N"],
+    },
+    Element {
+        name: "F",
+        sequence: "31121123222112",
+        decay: &["O"],
+    },
+    Element {
+        name: "Ne",
+        sequence: "111213322112",
+        decay: &["F"],
+    },
+    Element {
+        name: "Na",
+        sequence: "123222112",
+        decay: &["Ne"],
+    },
+    Element {
+        name: "Mg",
+        sequence: "3113322112",
+        decay: &["Pm", "Na"],
+    },
+    Element {
+        name: "Al",
+        sequence: "1113222112",
+        decay: &["Mg"],
+    },
+    Element {
+        name: "Si",
+        sequence: "1322112",
+        decay: &["Al"],
+    },
+    Element {
+        name: "P",
+        sequence: "311311222112",
+        decay: &["Ho", "Si"],
+    },
+    Element {
+        name: "S",
+        sequence: "1113122112",
+        decay: &["P"],
+    },
+    Element {
+        name: "Cl",
+        sequence: "132112",
+        decay: &["S"],
+    },
+    Element {
+        name: "Ar",
+        sequence: "3112",
+        decay: &["Cl"],
+    },
+    Element {
+        name: "K",
+        sequence: "1112",
+        decay: &["Ar"],
+    },
+    Element {
+        name: "Ca",
+        sequence: "12",
+        decay: &["K"],
+    },
+    Element {
+        name: "Sc",
+        sequence: "3113112221133112",
+        decay: &["Ho", "Pa", "H", "Ca", "Co"],
+    },
+    Element {
+        name: "Ti",
+        sequence: "11131221131112",
+        decay: &["Sc"],
+    },
+    Element {
+        name: "V",
+        sequence: "13211312",
+        decay: &["Ti"],
+    },
+    Element {
+        name: "Cr",
+        sequence: "31132",
+        decay: &["V"],
+    },
+    Element {
+        name: "Mn",
+        sequence: "111311222112",
+        decay: &["Cr", "Si"],
+    },
+    Element {
+        name: "Fe",
+        sequence: "13122112",
+        decay: &["Mn"],
+    },
+    Element {
+        name: "Co",
+        sequence: "32112",
+        decay: &["Fe"],
+    },
+    Element {
+        name: "Ni",
+        sequence: "11133112",
+        decay: &["Zn", "Co"],
+    },
+    Element {
+        name: "Cu",
+        sequence: "131112",
+        decay: &["Ni"],
+    },
+    Element {
+        name: "Zn",
+        sequence: "312",
+        decay: &["Cu"],
+    },
+    Element {
+        name: "Ga",
+        sequence: "13221133122211332",
+        decay: &["Eu", "Ca", "Ac", "H", "Ca", "Zn"],
+    },
+    Element {
+        name: "Ge",
+        sequence: "31131122211311122113222",
+        decay: &["Ho", "Ga"],
+    },
+    Element {
+        name: "As",
+        sequence: "11131221131211322113322112",
+        decay: &["Ge", "Na"],
+    },
+    Element {
+        name: "Se",
+        sequence: "13211321222113222112",
+        decay: &["As"],
+    },
+    Element {
+        name: "Br",
+        sequence: "3113112211322112",
+        decay: &["Se"],
+    },
+    Element {
+        name: "Kr",
+        sequence: "11131221222112",
+        decay: &["Br"],
+    },
+    Element {
+        name: "Rb",
+        sequence: "1321122112",
+        decay: &["Kr"],
+    },
+    Element {
+        name: "Sr",
+        sequence: "3112112",
+        decay: &["Rb"],
+    },
+    Element {
+        name: "Y",
+        sequence: "1112133",
+        decay: &["Sr", "U"],
+    },
+    Element {
+        name: "Zr",
+        sequence: "12322211331222113112211",
+        decay: &["Y", "H", "Ca", "Tc"],
+    },
+    Element {
+        name: "Nb",
+        sequence: "1113122113322113111221131221",
+        decay: &["Er", "Zr"],
+    },
+    Element {
+        name: "Mo",
+        sequence: "13211322211312113211",
+        decay: &["Nb"],
+    },
+    Element {
+        name: "Tc",
+        sequence: "311322113212221",
+        decay: &["Mo"],
+    },
+    Element {
+        name: "Ru",
+        sequence: "132211331222113112211",
+        decay: &["Eu", "Ca", "Tc"],
+    },
+    Element {
+        name: "Rh",
+        sequence: "311311222113111221131221",
+        decay: &["Ho", "Ru"],
+    },
+    Element {
+        name: "Pd",
+        sequence: "111312211312113211",
+        decay: &["Rh"],
+    },
+    Element {
+        name: "Ag",
+        sequence: "132113212221",
+        decay: &["Pd"],
+    },
+    Element {
+        name: "Cd",
+        sequence: "3113112211",
+        decay: &["Ag"],
+    },
+    Element {
+        name: "In",
+        sequence: "11131221",
+        decay: &["Cd"],
+    },
+    Element {
+        name: "Sn",
+        sequence: "13211",
+        decay: &["In"],
+    },
+    Element {
+        name: "Sb",
+        sequence: "3112221",
+        decay: &["Pm", "Sn"],
+    },
+    Element {
+        name: "Te",
+        sequence: "1322113312211",
+        decay: &["Eu", "Ca", "Sb"],
+    },
+    Element {
+        name: "I",
+        sequence: "311311222113111221",
+        decay: &["Ho", "Te"],
+    },
+    Element {
+        name: "Xe",
+        sequence: "11131221131211",
+        decay: &["I"],
+    },
+    Element {
+        name: "Cs",
+        sequence: "13211321",
+        decay: &["Xe"],
+    },
+    Element {
+        name: "Ba",
+        sequence: "311311",
+        decay: &["Cs"],
+    },
+    Element {
+        name: "La",
+        sequence: "11131",
+        decay: &["Ba"],
+    },
+    Element {
+        name: "Ce",
+        sequence: "1321133112",
+        decay: &["La", "H", "Ca", "Co"],
+    },
+    Element {
+        name: "Pr",
+        sequence: "31131112",
+        decay: &["Ce"],
+    },
+    Element {
+        name: "Nd",
+        sequence: "111312",
+        decay: &["Pr"],
+    },
+    Element {
+        name: "Pm",
+        sequence: "132",
+        decay: &["Nd"],
+    },
+    Element {
+        name: "Sm",
+        sequence: "311332",
+        decay: &["Pm", "Ca", "Zn"],
+    },
+    Element {
+        name: "Eu",
+        sequence: "1113222",
+        decay: &["Sm"],
+    },
+    Element {
+        name: "Gd",
+        sequence: "13221133112",
+        decay: &["Eu", "Ca", "Co"],
+    },
+    Element {
+        name: "Tb",
+        sequence: "3113112221131112",
+        decay: &["Ho", "Gd"],
+    },
+    Element {
+        name: "Dy",
+        sequence: "111312211312",
+        decay: &["Tb"],
+    },
+    Element {
+        name: "Ho",
+        sequence: "1321132",
+        decay: &["Dy"],
+    },
+    Element {
+        name: "Er",
+        sequence: "311311222",
+        decay: &["Ho", "Pm"],
+    },
+    Element {
+        name: "Tm",
+        sequence: "11131221133112",
+        decay: &["Er", "Ca", "Co"],
+    },
+    Element {
+        name: "Yb",
+        sequence: "1321131112",
+        decay: &["Tm"],
+    },
+    Element {
+        name: "Lu",
+        sequence: "311312",
+        decay: &["Yb"],
+    },
+    Element {
+        name: "Hf",
+        sequence: "11132",
+        decay: &["Lu"],
+    },
+    Element {
+        name: "Ta",
+        sequence: "13112221133211322112211213322113",
+        decay: &["Hf", "Pa", "H", "Ca", "W"],
+    },
+    Element {
+        name: "W",
+        sequence: "312211322212221121123222113",
+        decay: &["Ta"],
+    },
+    Element {
+        name: "Re",
+        sequence: "111312211312113221133211322112211213322113",
+        decay: &["Ge", "Ca", "W"],
+    },
+    Element {
+        name: "Os",
+        sequence: "1321132122211322212221121123222113",
+        decay: &["Re"],
+    },
+    Element {
+        name: "Ir",
+        sequence: "3113112211322112211213322113",
+        decay: &["Os"],
+    },
+    Element {
+        name: "Pt",
+        sequence: "111312212221121123222113",
+        decay: &["Ir"],
+    },
+    Element {
+        name: "Au",
+        sequence: "132112211213322113",
+        decay: &["Pt"],
+    },
+    Element {
+        name: "Hg",
+        sequence: "31121123222113",
+        decay: &["Au"],
+    },
+    Element {
+        name: "Tl",
+        sequence: "111213322113",
+        decay: &["Hg"],
+    },
+    Element {
+        name: "Pb",
+        sequence: "123222113",
+        decay: &["Tl"],
+    },
+    Element {
+        name: "Bi",
+        sequence: "3113322113",
+        decay: &["Pm", "Pb"],
+    },
+    Element {
+        name: "Po",
+        sequence: "1113222113",
+        decay: &["Bi"],
+    },
+    Element {
+        name: "At",
+        sequence: "1322113",
+        decay: &["Po"],
+    },
+    Element {
+        name: "Rn",
+        sequence: "311311222113",
+        decay: &["Ho", "At"],
+    },
+    Element {
+        name: "Fr",
+        sequence: "1113122113",
+        decay: &["Rn"],
+    },
+    Element {
+        name: "Ra",
+        sequence: "132113",
+        decay: &["Fr"],
+    },
+    Element {
+        name: "Ac",
+        sequence: "3113",
+        decay: &["Ra"],
+    },
+    Element {
+        name: "Th",
+        sequence: "1113",
+        decay: &["Ac"],
+    },
+    Element {
+        name: "Pa",
+        sequence: "13",
+        decay: &["Th"],
+    },
+    Element {
+        name: "U",
+        sequence: "3",
+        decay: &["Pa"],
+    },
+];
+
+/// Position in [`ELEMENTS`] of the element called `name`.
+pub fn position(name: &str) -> Option<usize> {
+    ELEMENTS.iter().position(|element| element.name == name)
+}
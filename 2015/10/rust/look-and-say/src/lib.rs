@@ -0,0 +1,135 @@
+use std::error::Error;
+use std::fmt;
+
+use aoc_common::{Answer, Input, Solution};
+
+pub mod chemistry;
+pub mod elements;
+pub mod stream;
+
+use chemistry::Chemistry;
+
+/// Reads the digits out loud, one run of equal digits at a time.
+///
+/// # Example
+/// ```rust
+/// use look_and_say::look_and_say;
+///
+/// assert_eq!(look_and_say("1".to_string()), "11");
+/// assert_eq!(look_and_say("111221".to_string()), "312211");
+/// ```
+pub fn look_and_say(input: String) -> String {
+    stream::said(input.bytes()).map(char::from).collect()
+}
+
+/// Why the length of a sequence can't be told.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LengthError {
+    /// The seed has something other than a digit at the given column.
+    NotADigit {
+        column: usize,
+        found: char,
+    },
+    TooLong,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LengthError::NotADigit { column, found } => {
+                write!(f, "column {}: expected a digit, found `{}`", column, found)
+            }
+            LengthError::TooLong => write!(f, "too long to count"),
+        }
+    }
+}
+
+impl Error for LengthError {}
+
+/// The digits of `seed`, as bytes.
+///
+/// # Example
+/// ```rust
+/// use look_and_say::{digits, LengthError};
+///
+/// assert_eq!(digits("112"), Ok(&b"112"[..]));
+/// assert_eq!(digits("1a"), Err(LengthError::NotADigit { column: 2, found: 'a' }));
+/// ```
+pub fn digits(seed: &str) -> Result<&[u8], LengthError> {
+    match seed.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((i, found)) => Err(LengthError::NotADigit {
+            column: seed[..i].chars().count() + 1,
+            found,
+        }),
+        None => Ok(seed.as_bytes()),
+    }
+}
+
+/// A sequence, told by how many of each piece of its [`Chemistry`] it is made of.
+#[derive(Debug, Clone)]
+pub struct Sequence {
+    pub chemistry: Chemistry,
+    /// How many of each piece there are, indexed by id.
+    pub counts: Vec<u128>,
+    pub length: u128,
+}
+
+/// The sequence after applying `look_and_say` to `seed` the given number of times.
+///
+/// # Example
+/// ```rust
+/// use look_and_say::sequence_after;
+///
+/// let sequence = sequence_after("1", 8).unwrap();
+/// assert_eq!(sequence.length, 14);
+/// assert_eq!(sequence.counts.iter().sum::<u128>(), 2);
+/// ```
+pub fn sequence_after(seed: &str, iterations: usize) -> Result<Sequence, LengthError> {
+    let mut chemistry = Chemistry::new();
+    let counts = chemistry.compound(digits(seed)?);
+    let counts = chemistry
+        .after(&counts, iterations)
+        .ok_or(LengthError::TooLong)?;
+    let length = chemistry.length(&counts).ok_or(LengthError::TooLong)?;
+    Ok(Sequence {
+        chemistry,
+        counts,
+        length,
+    })
+}
+
+/// Length of the sequence after applying `look_and_say` to `seed` the given number of times.
+///
+/// # Example
+/// ```rust
+/// use look_and_say::{length_after, LengthError};
+///
+/// assert_eq!(length_after("1", 5), Ok(6));
+/// assert_eq!(length_after("1", 60), Ok(16530884));
+/// assert_eq!(length_after("1", 1000), Err(LengthError::TooLong));
+/// assert_eq!(length_after("abc", 5), Err(LengthError::NotADigit { column: 1, found: 'a' }));
+/// ```
+pub fn length_after(seed: &str, iterations: usize) -> Result<u128, LengthError> {
+    Ok(sequence_after(seed, iterations)?.length)
+}
+
+pub struct Puzzle;
+
+impl Puzzle {
+    fn length(input: &Input, iterations: usize) -> Answer {
+        let seed = input.read_to_string()?;
+        Ok(length_after(seed.trim(), iterations)?.to_string())
+    }
+}
+
+impl Solution for Puzzle {
+    const INPUT: Option<&'static str> = Some("1113122113");
+
+    fn part_one(input: &Input) -> Answer {
+        Self::length(input, 40)
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Self::length(input, 50)
+    }
+}
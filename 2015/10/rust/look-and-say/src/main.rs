@@ -1,21 +1,41 @@
-use itertools::Itertools;
+use clap::Parser;
+use look_and_say::{sequence_after, stream, Sequence};
 
-mod io;
+mod args;
 
-fn look_and_say(input: String) -> String {
-    input
-        .chars()
-        .group_by(|c| *c)
-        .into_iter()
-        .map(|(c, grp)| format!("{}{}", grp.collect::<Vec<_>>().len(), c))
-        .join("")
+use args::Args;
+
+fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let Sequence {
+        chemistry,
+        counts,
+        length,
+    } = sequence_after(&args.seed, args.iterations)?;
+
+    let prefix: String = stream::after(args.seed.as_bytes(), args.iterations)
+        .take(args.prefix)
+        .map(char::from)
+        .collect();
+    let ellipsis = if length > args.prefix as u128 {
+        "..."
+    } else {
+        ""
+    };
+    println!("{}{}: {}", prefix, ellipsis, length);
+
+    if args.elements {
+        for (id, &count) in counts.iter().enumerate().filter(|(_, &count)| count > 0) {
+            let piece = String::from_utf8_lossy(chemistry.piece(id));
+            let name = chemistry.name(id).unwrap_or("-");
+            println!("{:>2} {} x {}", name, piece, count);
+        }
+    }
+    Ok(())
 }
 
 fn main() {
-    let mut last = io::seed();
-    for _ in 0..io::iterations() {
-        last = look_and_say(last);
+    if let Err(e) = run(Args::parse()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
     }
-
-    println!("{}: {}", last, last.len());
 }
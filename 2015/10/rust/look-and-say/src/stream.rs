@@ -0,0 +1,65 @@
+//! Look-and-say one digit at a time, without ever holding a whole sequence.
+
+use std::iter::Peekable;
+
+/// Reads `digits` out loud as they come, one run of equal digits at a time.
+pub struct Said<I: Iterator<Item = u8>> {
+    digits: Peekable<I>,
+    /// What's left to say of the last run, backwards.
+    pending: Vec<u8>,
+}
+
+impl<I: Iterator<Item = u8>> Iterator for Said<I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if let Some(digit) = self.pending.pop() {
+            return Some(digit);
+        }
+        let digit = self.digits.next()?;
+        let mut count = 1usize;
+        while self.digits.next_if_eq(&digit).is_some() {
+            count += 1;
+        }
+        self.pending.push(digit);
+        loop {
+            self.pending.push(b'0' + (count % 10) as u8);
+            count /= 10;
+            if count == 0 {
+                break;
+            }
+        }
+        self.pending.pop()
+    }
+}
+
+/// Reads `digits` out loud.
+///
+/// # Example
+/// ```rust
+/// use look_and_say::stream::said;
+///
+/// assert_eq!(said(*b"111221").collect::<Vec<u8>>(), b"312211");
+/// assert_eq!(said(*b"333333333333").collect::<Vec<u8>>(), b"123");
+/// ```
+pub fn said<I: IntoIterator<Item = u8>>(digits: I) -> Said<I::IntoIter> {
+    Said {
+        digits: digits.into_iter().peekable(),
+        pending: vec![],
+    }
+}
+
+/// The sequence `days` days after `seed`, each day only keeping the run it is reading out.
+///
+/// # Example
+/// ```rust
+/// use look_and_say::stream::after;
+///
+/// let prefix: Vec<u8> = after(b"1", 50).take(10).collect();
+/// assert_eq!(prefix, b"3113112221");
+/// ```
+pub fn after(seed: &[u8], days: usize) -> Box<dyn Iterator<Item = u8> + '_> {
+    (0..days).fold(Box::new(seed.iter().copied()), |digits, _| {
+        Box::new(said(digits))
+    })
+}
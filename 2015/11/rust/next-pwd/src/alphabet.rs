@@ -0,0 +1,120 @@
+use std::fmt;
+
+use clap::{builder::PossibleValue, ValueEnum};
+
+/// The characters passwords are made of, in the order they count up in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    symbols: Vec<char>,
+}
+
+/// Why some characters can't be an alphabet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlphabetError {
+    Empty,
+    Duplicate(char),
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlphabetError::Empty => write!(f, "the alphabet has no characters"),
+            AlphabetError::Duplicate(c) => write!(f, "{:?} is in the alphabet twice", c),
+        }
+    }
+}
+
+impl std::error::Error for AlphabetError {}
+
+impl Alphabet {
+    /// The characters of `symbols`, smallest first.
+    ///
+    /// # Example
+    /// ```rust
+    /// use next_pwd::alphabet::{Alphabet, AlphabetError};
+    ///
+    /// assert_eq!(Alphabet::new("01").unwrap().symbol(1), '1');
+    /// assert_eq!(Alphabet::new("0110"), Err(AlphabetError::Duplicate('1')));
+    /// ```
+    pub fn new(symbols: &str) -> Result<Self, AlphabetError> {
+        let symbols: Vec<char> = symbols.chars().collect();
+        if symbols.is_empty() {
+            return Err(AlphabetError::Empty);
+        }
+        for (i, c) in symbols.iter().enumerate() {
+            if symbols[..i].contains(c) {
+                return Err(AlphabetError::Duplicate(*c));
+            }
+        }
+        Ok(Alphabet { symbols })
+    }
+
+    /// Number of characters.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// The character at `index`, counting from the smallest.
+    pub fn symbol(&self, index: usize) -> char {
+        self.symbols[index]
+    }
+
+    /// Where `c` is in the alphabet, if it is.
+    pub fn index(&self, c: char) -> Option<usize> {
+        self.symbols.iter().position(|&symbol| symbol == c)
+    }
+}
+
+/// Alphabets that come ready to use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Charset {
+    #[default]
+    Lowercase,
+    Uppercase,
+    Digits,
+    Letters,
+    Alphanumeric,
+}
+
+impl Charset {
+    pub const ALL: [Charset; 5] = [
+        Charset::Lowercase,
+        Charset::Uppercase,
+        Charset::Digits,
+        Charset::Letters,
+        Charset::Alphanumeric,
+    ];
+
+    pub fn alphabet(self) -> Alphabet {
+        let symbols = match self {
+            Charset::Lowercase => ('a'..='z').collect(),
+            Charset::Uppercase => ('A'..='Z').collect(),
+            Charset::Digits => ('0'..='9').collect(),
+            Charset::Letters => ('A'..='Z').chain('a'..='z').collect(),
+            Charset::Alphanumeric => ('0'..='9').chain('A'..='Z').chain('a'..='z').collect(),
+        };
+        Alphabet { symbols }
+    }
+}
+
+impl ValueEnum for Charset {
+    fn value_variants<'a>() -> &'a [Self] {
+        &Self::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Lowercase => PossibleValue::new("lowercase").help("a to z, like the puzzle"),
+            Self::Uppercase => PossibleValue::new("uppercase").help("A to Z"),
+            Self::Digits => PossibleValue::new("digits").help("0 to 9"),
+            Self::Letters => PossibleValue::new("letters").help("A to Z, then a to z"),
+            Self::Alphanumeric => {
+                PossibleValue::new("alphanumeric").help("0 to 9, A to Z, then a to z")
+            }
+        })
+    }
+}
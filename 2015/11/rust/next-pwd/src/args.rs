@@ -0,0 +1,38 @@
+use clap::Parser;
+use next_pwd::alphabet::Charset;
+
+/// Finds the next passwords Santa is allowed to pick
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// Current password
+    pub password: String,
+
+    /// How many passwords to find
+    #[arg(default_value_t = 1)]
+    pub number: usize,
+
+    /// Count the allowed passwords from the current one up to this one instead
+    #[arg(long)]
+    pub until: Option<String>,
+
+    /// Characters passwords are made of
+    #[arg(long, value_enum, default_value_t = Charset::default())]
+    pub charset: Charset,
+
+    /// Characters passwords are made of, smallest first, instead of a charset
+    #[arg(long)]
+    pub symbols: Option<String>,
+
+    /// Characters passwords may not contain
+    #[arg(long, default_value = "iol")]
+    pub forbid: String,
+
+    /// Length of the straight passwords must contain, in the order of the alphabet, 0 for none
+    #[arg(long, default_value_t = 3)]
+    pub straight: usize,
+
+    /// How many pairs of different characters passwords must contain
+    #[arg(long, default_value_t = 2)]
+    pub pairs: usize,
+}
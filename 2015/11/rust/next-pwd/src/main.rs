@@ -1,18 +1,48 @@
-use next_pwd::next_passwords;
+use clap::Parser;
+use next_pwd::alphabet::Alphabet;
+use next_pwd::policy::{Forbidden, Pairs, Straight};
+use next_pwd::{Password, PasswordPolicy, Passwords};
 
-mod io;
+mod args;
 
-fn main() {
-    env_logger::init();
+use args::Args;
+
+fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let alphabet = match &args.symbols {
+        Some(symbols) => Alphabet::new(symbols)?,
+        None => args.charset.alphabet(),
+    };
+    let policy = Forbidden::new(&args.forbid)
+        .and(Straight::new(args.straight, alphabet.clone()))
+        .and(Pairs::new(args.pairs));
+    let passwords = Passwords::new(alphabet, policy);
+    let parse = |s: &str| -> Result<Password, String> {
+        s.parse().map_err(|_| format!("invalid password: {:?}", s))
+    };
+    let password = parse(&args.password)?;
+
+    if let Some(until) = &args.until {
+        println!("{}", passwords.count(&password, &parse(until)?)?);
+        return Ok(());
+    }
 
     let mut found = false;
-    for next_pwd in next_passwords(&io::read_password(), io::number_of_passwords()) {
+    for next_pwd in passwords.iter_after(&password)?.take(args.number) {
         found = true;
         println!("{}", next_pwd);
     }
 
     if !found {
-        eprintln!("Error: There is no next viable password");
+        return Err("there is no next viable password".into());
+    }
+    Ok(())
+}
+
+fn main() {
+    env_logger::init();
+
+    if let Err(e) = run(Args::parse()) {
+        eprintln!("Error: {}", e);
         std::process::exit(1);
     }
 }
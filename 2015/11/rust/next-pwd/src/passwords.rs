@@ -0,0 +1,367 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use log::debug;
+
+use crate::alphabet::Alphabet;
+use crate::policy::PasswordPolicy;
+
+/// A password of any length.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Password {
+    pub value: Vec<char>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParsePasswordError;
+
+impl FromStr for Password {
+    type Err = ParsePasswordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.is_empty() {
+            true => Err(ParsePasswordError),
+            false => Ok(Password {
+                value: s.chars().collect(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Password {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.iter().try_for_each(|c| write!(f, "{}", c))
+    }
+}
+
+/// Why passwords can't be looked for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordError {
+    /// A character the passwords can't be made of.
+    NotInAlphabet(char),
+    /// Both ends of a range must be as long.
+    LengthMismatch { from: usize, to: usize },
+    /// More passwords than fit in a `u128`.
+    TooMany,
+}
+
+impl fmt::Display for PasswordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PasswordError::NotInAlphabet(c) => write!(f, "{:?} is not in the alphabet", c),
+            PasswordError::LengthMismatch { from, to } => write!(
+                f,
+                "can't go from a password of {} characters to one of {}",
+                from, to
+            ),
+            PasswordError::TooMany => write!(f, "too many passwords to count"),
+        }
+    }
+}
+
+impl std::error::Error for PasswordError {}
+
+/// Counts by characters left and state, `None` when there are too many to count.
+type Completions<S> = HashMap<(usize, S), Option<u128>>;
+
+/// The passwords made of `alphabet` that `policy` allows, in the order they count up in.
+pub struct Passwords<P: PasswordPolicy> {
+    alphabet: Alphabet,
+    policy: P,
+    /// How many ways there are to finish a password.
+    completions: RefCell<Completions<P::State>>,
+}
+
+impl<P: PasswordPolicy> Passwords<P> {
+    pub fn new(alphabet: Alphabet, policy: P) -> Self {
+        Passwords {
+            alphabet,
+            policy,
+            completions: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Positions of the characters of `password` in the alphabet.
+    fn indices(&self, password: &Password) -> Result<Vec<usize>, PasswordError> {
+        password
+            .value
+            .iter()
+            .map(|&c| {
+                self.alphabet
+                    .index(c)
+                    .ok_or(PasswordError::NotInAlphabet(c))
+            })
+            .collect()
+    }
+
+    fn step(&self, state: &P::State, symbol: usize) -> Option<P::State> {
+        self.policy.step(state, self.alphabet.symbol(symbol))
+    }
+
+    /// How many allowed passwords go on from `state` with `left` more characters.
+    fn completions(&self, left: usize, state: &P::State) -> Option<u128> {
+        if left == 0 {
+            return Some(self.policy.accepts(state) as u128);
+        }
+        let key = (left, state.clone());
+        if let Some(&count) = self.completions.borrow().get(&key) {
+            return count;
+        }
+        let count = (0..self.alphabet.len())
+            .filter_map(|symbol| self.step(state, symbol))
+            .try_fold(0u128, |count, next| {
+                count.checked_add(self.completions(left - 1, &next)?)
+            });
+        self.completions.borrow_mut().insert(key, count);
+        count
+    }
+
+    /// Whether any allowed password goes on from `state` with `left` more characters.
+    fn can_finish(&self, left: usize, state: &P::State) -> bool {
+        self.completions(left, state) != Some(0)
+    }
+
+    /// The smallest allowed password starting with `prefix`, which ends in `state`, that is
+    /// `length` characters long.
+    fn smallest(&self, mut prefix: Vec<usize>, mut state: P::State, length: usize) -> Password {
+        while prefix.len() < length {
+            let left = length - prefix.len() - 1;
+            let (symbol, next) = (0..self.alphabet.len())
+                .filter_map(|symbol| Some((symbol, self.step(&state, symbol)?)))
+                .find(|(_, next)| self.can_finish(left, next))
+                .expect("a prefix that can be finished");
+            prefix.push(symbol);
+            state = next;
+        }
+        Password {
+            value: prefix
+                .into_iter()
+                .map(|i| self.alphabet.symbol(i))
+                .collect(),
+        }
+    }
+
+    pub fn is_valid(&self, password: &Password) -> bool {
+        password
+            .value
+            .iter()
+            .all(|&c| self.alphabet.index(c).is_some())
+            && self.policy.allows(&password.to_string())
+    }
+
+    /// The first allowed password that comes after `password`, as long as it is, if there's any.
+    ///
+    /// Rather than trying every password in between, this skips every password starting
+    /// with characters that can't be finished into an allowed one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use next_pwd::santa;
+    ///
+    /// let passwords = santa();
+    /// let next = passwords.next_after(&"ghijklmn".parse().unwrap()).unwrap();
+    /// assert_eq!(next.unwrap().to_string(), "ghjaabcc");
+    /// ```
+    pub fn next_after(&self, password: &Password) -> Result<Option<Password>, PasswordError> {
+        let symbols = self.indices(password)?;
+        let mut states = vec![self.policy.start()];
+        for &symbol in &symbols {
+            match self.step(states.last().unwrap(), symbol) {
+                Some(state) => states.push(state),
+                None => break,
+            }
+        }
+
+        // change the last character that can be changed into one that can still be finished,
+        // then finish as small as possible
+        for i in (0..states.len().min(symbols.len())).rev() {
+            let left = symbols.len() - i - 1;
+            let found = (symbols[i] + 1..self.alphabet.len())
+                .filter_map(|symbol| Some((symbol, self.step(&states[i], symbol)?)))
+                .find(|(_, state)| self.can_finish(left, state));
+            if let Some((symbol, state)) = found {
+                debug!("changing character {} of {}", i, password);
+                let prefix = [&symbols[..i], &[symbol]].concat();
+                return Ok(Some(self.smallest(prefix, state, symbols.len())));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Every allowed password that comes after `password`, as long as it is.
+    pub fn iter_after(
+        &self,
+        password: &Password,
+    ) -> Result<PasswordIterator<'_, P>, PasswordError> {
+        self.indices(password)?;
+        Ok(PasswordIterator {
+            passwords: self,
+            pwd: Some(password.clone()),
+        })
+    }
+
+    /// How many allowed passwords there are up to `password`, included.
+    fn count_up_to(&self, password: &[usize]) -> Option<u128> {
+        let mut count = 0u128;
+        let mut state = self.policy.start();
+        for (i, &symbol) in password.iter().enumerate() {
+            let left = password.len() - i - 1;
+            for smaller in 0..symbol {
+                if let Some(next) = self.step(&state, smaller) {
+                    count = count.checked_add(self.completions(left, &next)?)?;
+                }
+            }
+            match self.step(&state, symbol) {
+                Some(next) => state = next,
+                None => return Some(count),
+            }
+        }
+        Some(count + self.policy.accepts(&state) as u128)
+    }
+
+    /// How many allowed passwords there are from `from` to `to`, both included, without
+    /// going through them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use next_pwd::santa;
+    ///
+    /// let passwords = santa();
+    /// let (from, to) = ("aaaaaaaa".parse().unwrap(), "zzzzzzzz".parse().unwrap());
+    /// assert_eq!(passwords.count(&from, &to), Ok(6_451_585));
+    /// ```
+    pub fn count(&self, from: &Password, to: &Password) -> Result<u128, PasswordError> {
+        let (from_symbols, to_symbols) = (self.indices(from)?, self.indices(to)?);
+        if from_symbols.len() != to_symbols.len() {
+            return Err(PasswordError::LengthMismatch {
+                from: from_symbols.len(),
+                to: to_symbols.len(),
+            });
+        }
+        if from_symbols > to_symbols {
+            return Ok(0);
+        }
+        let up_to = |symbols| self.count_up_to(symbols).ok_or(PasswordError::TooMany);
+        Ok(up_to(&to_symbols)? - up_to(&from_symbols)? + self.is_valid(from) as u128)
+    }
+}
+
+/// Allowed passwords, one after the other.
+pub struct PasswordIterator<'a, P: PasswordPolicy> {
+    passwords: &'a Passwords<P>,
+    pwd: Option<Password>,
+}
+
+impl<P: PasswordPolicy> Iterator for PasswordIterator<'_, P> {
+    type Item = Password;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pwd = self.pwd.take()?;
+        // the characters were checked up front
+        self.pwd = self.passwords.next_after(&pwd).unwrap();
+        self.pwd.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::Charset;
+    use crate::policy::{Forbidden, Pairs, Straight};
+    use crate::santa;
+
+    fn pwd(s: &str) -> Password {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_password_iterator_in_for_loop() {
+        let passwords = santa();
+        let mut next_pwds = vec![];
+        for next_pwd in passwords.iter_after(&pwd("zzzzaaaa")).unwrap() {
+            next_pwds.push(next_pwd);
+        }
+
+        assert_eq!(next_pwds.len(), 29);
+        assert_eq!(passwords.count(&pwd("zzzzaaab"), &pwd("zzzzzzzz")), Ok(29));
+    }
+
+    #[test]
+    fn test_next_after() {
+        let passwords = santa();
+        let next = |s| passwords.next_after(&pwd(s)).unwrap().unwrap().to_string();
+        assert_eq!(next("abcdefgh"), "abcdffaa");
+        assert_eq!(next("ghijklmn"), "ghjaabcc");
+        assert_eq!(next("ihijklmn"), "jaaaabcc");
+        assert_eq!(passwords.next_after(&pwd("zzzzzzzz")), Ok(None));
+        assert_eq!(
+            passwords.next_after(&pwd("abcdefgH")),
+            Err(PasswordError::NotInAlphabet('H'))
+        );
+    }
+
+    /// Every allowed password, the slow way.
+    fn brute_force<P: PasswordPolicy>(alphabet: &Alphabet, policy: &P, length: u32) -> Vec<String> {
+        let n = alphabet.len();
+        (0..n.pow(length))
+            .map(|mut i| {
+                let mut password = vec![];
+                for _ in 0..length {
+                    password.push(alphabet.symbol(i % n));
+                    i /= n;
+                }
+                password.into_iter().rev().collect::<String>()
+            })
+            .filter(|password| policy.allows(password))
+            .collect()
+    }
+
+    #[test]
+    fn test_digits() {
+        let alphabet = Charset::Digits.alphabet();
+        let policy = Forbidden::new("7")
+            .and(Straight::new(3, alphabet.clone()))
+            .and(Pairs::new(1));
+        let expected = brute_force(&alphabet, &policy, 5);
+        let passwords = Passwords::new(alphabet, policy);
+
+        let all: Vec<String> = passwords
+            .iter_after(&pwd("00000"))
+            .unwrap()
+            .map(|p| p.to_string())
+            .collect();
+        assert_eq!(all, expected);
+        assert_eq!(
+            passwords.count(&pwd("00000"), &pwd("99999")),
+            Ok(expected.len() as u128)
+        );
+        assert_eq!(
+            passwords.count(&pwd("12000"), &pwd("45678")),
+            Ok(expected
+                .iter()
+                .filter(|&p| ("12000"..="45678").contains(&p.as_str()))
+                .count() as u128)
+        );
+        assert_eq!(
+            passwords.count(&pwd("9"), &pwd("00")),
+            Err(PasswordError::LengthMismatch { from: 1, to: 2 })
+        );
+    }
+
+    #[test]
+    fn test_mixed_case() {
+        let alphabet = Alphabet::new("aAbBcC").unwrap();
+        let passwords = Passwords::new(alphabet, Pairs::new(2));
+        assert_eq!(
+            passwords.next_after(&pwd("aaBa")).unwrap(),
+            Some(pwd("aaBB"))
+        );
+        assert_eq!(
+            passwords.next_after(&pwd("aaBB")).unwrap(),
+            Some(pwd("aacc"))
+        );
+        assert_eq!(passwords.count(&pwd("aaaa"), &pwd("CCCC")), Ok(30));
+    }
+}
@@ -0,0 +1,245 @@
+//! Rules passwords must follow, checked one character at a time so that whole ranges of
+//! passwords can be ruled out, or counted, by their beginning alone.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::alphabet::Alphabet;
+
+/// A rule on passwords, read as a state machine over their characters.
+pub trait PasswordPolicy {
+    /// What the rule remembers of the characters read so far.
+    type State: Clone + Eq + Hash + Debug;
+
+    /// State before the first character.
+    fn start(&self) -> Self::State;
+
+    /// State after reading `c`, or `None` if no password going on like this is allowed.
+    fn step(&self, state: &Self::State, c: char) -> Option<Self::State>;
+
+    /// Whether a password that ends in `state` is allowed.
+    fn accepts(&self, state: &Self::State) -> bool;
+
+    /// Whether `password` is allowed.
+    fn allows(&self, password: &str) -> bool {
+        password
+            .chars()
+            .try_fold(self.start(), |state, c| self.step(&state, c))
+            .is_some_and(|state| self.accepts(&state))
+    }
+
+    /// Both this rule and `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use next_pwd::alphabet::Charset;
+    /// use next_pwd::policy::{Forbidden, Pairs, PasswordPolicy, Straight};
+    ///
+    /// let straight = Straight::new(3, Charset::Lowercase.alphabet());
+    /// let policy = Forbidden::new("iol").and(straight).and(Pairs::new(2));
+    /// assert!(!policy.allows("hijklmmn"));
+    /// assert!(!policy.allows("abbceffg"));
+    /// assert!(!policy.allows("abbcegjk"));
+    /// assert!(policy.allows("abcdffaa"));
+    /// ```
+    fn and<P: PasswordPolicy>(self, other: P) -> Both<Self, P>
+    where
+        Self: Sized,
+    {
+        Both(self, other)
+    }
+}
+
+/// Two rules at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Both<A, B>(pub A, pub B);
+
+impl<A: PasswordPolicy, B: PasswordPolicy> PasswordPolicy for Both<A, B> {
+    type State = (A::State, B::State);
+
+    fn start(&self) -> Self::State {
+        (self.0.start(), self.1.start())
+    }
+
+    fn step(&self, (a, b): &Self::State, c: char) -> Option<Self::State> {
+        Some((self.0.step(a, c)?, self.1.step(b, c)?))
+    }
+
+    fn accepts(&self, (a, b): &Self::State) -> bool {
+        self.0.accepts(a) && self.1.accepts(b)
+    }
+}
+
+/// Characters a password may not contain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forbidden {
+    chars: Vec<char>,
+}
+
+impl Forbidden {
+    pub fn new(chars: &str) -> Self {
+        Forbidden {
+            chars: chars.chars().collect(),
+        }
+    }
+}
+
+impl PasswordPolicy for Forbidden {
+    type State = ();
+
+    fn start(&self) {}
+
+    fn step(&self, _: &(), c: char) -> Option<()> {
+        (!self.chars.contains(&c)).then_some(())
+    }
+
+    fn accepts(&self, _: &()) -> bool {
+        true
+    }
+}
+
+/// A run of at least `length` characters, each one coming right after the one before in the
+/// alphabet, like `abc` or `123`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Straight {
+    length: usize,
+    alphabet: Alphabet,
+}
+
+impl Straight {
+    pub fn new(length: usize, alphabet: Alphabet) -> Self {
+        Straight { length, alphabet }
+    }
+}
+
+/// How far along a [`Straight`] a password is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StraightState {
+    /// Where the last character is in the alphabet, if it is, and how long the run ending with
+    /// it is.
+    Running(Option<usize>, usize),
+    Found,
+}
+
+impl PasswordPolicy for Straight {
+    type State = StraightState;
+
+    fn start(&self) -> StraightState {
+        match self.length {
+            0 => StraightState::Found,
+            _ => StraightState::Running(None, 0),
+        }
+    }
+
+    fn step(&self, state: &StraightState, c: char) -> Option<StraightState> {
+        let StraightState::Running(last, run) = *state else {
+            return Some(StraightState::Found);
+        };
+        let index = self.alphabet.index(c);
+        let run = match (last, index) {
+            (Some(last), Some(index)) if index == last + 1 => run + 1,
+            _ => 1,
+        };
+        Some(match run >= self.length {
+            true => StraightState::Found,
+            false => StraightState::Running(index, run),
+        })
+    }
+
+    fn accepts(&self, state: &StraightState) -> bool {
+        *state == StraightState::Found
+    }
+}
+
+/// At least `count` pairs of the same character, like `aa` or `zz`, each one of a different
+/// character, so that no two of them overlap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pairs {
+    count: usize,
+}
+
+impl Pairs {
+    pub fn new(count: usize) -> Self {
+        Pairs { count }
+    }
+}
+
+/// The pairs a password has so far.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PairsState {
+    /// The last character, and the characters paired up so far, sorted.
+    Looking(Option<char>, Vec<char>),
+    Found,
+}
+
+impl PasswordPolicy for Pairs {
+    type State = PairsState;
+
+    fn start(&self) -> PairsState {
+        match self.count {
+            0 => PairsState::Found,
+            _ => PairsState::Looking(None, vec![]),
+        }
+    }
+
+    fn step(&self, state: &PairsState, c: char) -> Option<PairsState> {
+        let PairsState::Looking(last, pairs) = state else {
+            return Some(PairsState::Found);
+        };
+        let mut pairs = pairs.clone();
+        if *last == Some(c) {
+            if let Err(i) = pairs.binary_search(&c) {
+                pairs.insert(i, c);
+            }
+        }
+        Some(match pairs.len() >= self.count {
+            true => PairsState::Found,
+            false => PairsState::Looking(Some(c), pairs),
+        })
+    }
+
+    fn accepts(&self, state: &PairsState) -> bool {
+        *state == PairsState::Found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabet::Charset;
+
+    #[test]
+    fn test_straight() {
+        let straight = Straight::new(3, Charset::Alphanumeric.alphabet());
+        assert!(straight.allows("xyz"));
+        assert!(straight.allows("aabcd"));
+        assert!(straight.allows("0123"));
+        assert!(straight.allows("89A"));
+        assert!(!straight.allows("abd"));
+        assert!(!straight.allows("zab"));
+        assert!(!straight.allows("9:;"));
+        assert!(Straight::new(0, Charset::Digits.alphabet()).allows(""));
+
+        let reversed = Straight::new(3, Alphabet::new("cba").unwrap());
+        assert!(reversed.allows("cba"));
+        assert!(!reversed.allows("abc"));
+    }
+
+    #[test]
+    fn test_pairs() {
+        let pairs = Pairs::new(2);
+        assert!(pairs.allows("aabb"));
+        assert!(pairs.allows("xxyzzy"));
+        assert!(!pairs.allows("aaa"));
+        assert!(!pairs.allows("aabaa"));
+        assert!(Pairs::new(3).allows("aaBBcc"));
+        assert!(!Pairs::new(3).allows("aaBBaa"));
+    }
+
+    #[test]
+    fn test_forbidden() {
+        assert!(Forbidden::new("iol").allows("abc"));
+        assert!(!Forbidden::new("iol").allows("abco"));
+        assert!(Forbidden::new("").allows("iol"));
+    }
+}
@@ -1,4 +1,5 @@
-pub fn input() -> String {
-    let path = std::env::args().nth(1).expect("need file path");
-    std::fs::read_to_string(path).expect("error reading file")
+use aoc_common::Input;
+
+pub fn input() -> std::io::Result<String> {
+    Input::from_args().read_to_string()
 }
@@ -0,0 +1,173 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{digit1, space1};
+use nom::combinator::{map, map_res};
+use nom::{
+    character::complete::{alpha1, space0},
+    sequence::{preceded, terminated},
+    IResult,
+};
+
+use itertools::Itertools;
+
+use aoc_common::{Answer, Input, Solution};
+use aoc_graph::{Direction, Graph};
+
+#[derive(Debug)]
+pub struct Pairing<'a> {
+    pub first: &'a str,
+    pub second: &'a str,
+    pub gain: i32,
+}
+
+pub fn parse_pairing(input: &str) -> IResult<&str, Pairing<'_>> {
+    let (input, first) = terminated(alpha1, space0)(input)?;
+    let (input, mult) = preceded(
+        terminated(tag("would"), space1),
+        terminated(
+            alt((map(tag("gain"), |_| 1), map(tag("lose"), |_| -1))),
+            space1,
+        ),
+    )(input)?;
+
+    let (input, value) = map_res(digit1, |v: &str| v.parse::<i32>())(input)?;
+    let (input, second) = preceded(
+        preceded(
+            space0,
+            terminated(tag("happiness units by sitting next to"), space1),
+        ),
+        alpha1,
+    )(input)?;
+    Ok((
+        input,
+        Pairing {
+            first,
+            second,
+            gain: mult * value,
+        },
+    ))
+}
+
+/// Happiness each guest gains, or loses, when sat next to each other guest.
+pub type Table = Graph<i32>;
+
+pub fn table<S: AsRef<str>>(lines: &[S]) -> Result<Table, String> {
+    lines.iter().try_fold(Table::new(), |mut acc, line| {
+        let (_, pairing) = parse_pairing(line.as_ref())
+            .map_err(|_| format!("Invalid pairing: {}", line.as_ref()))?;
+        let (first, second) = (acc.add_node(pairing.first), acc.add_node(pairing.second));
+        acc.add_edge(first, second, pairing.gain, Direction::Directed);
+        Ok(acc)
+    })
+}
+
+/// Every distinct seating around the table, along with its total happiness and the happiness
+/// of its weakest link. Fails if the happiness of two guests sat next to each other is unknown.
+pub fn arrangements(table: &Table) -> Result<impl Iterator<Item = (Vec<&str>, i32, i32)>, String> {
+    let n = table.len();
+
+    // happiness of both guests of each pair, sat next to each other
+    let mut happiness = vec![vec![0; n]; n];
+    let weight = |from, to| {
+        table.weight(from, to).ok_or_else(|| {
+            format!(
+                "Missing pairing: {} next to {}",
+                table.name(from),
+                table.name(to)
+            )
+        })
+    };
+    for (l, r) in table.nodes().tuple_combinations() {
+        happiness[l][r] = weight(l, r)? + weight(r, l)?;
+        happiness[r][l] = happiness[l][r];
+    }
+
+    // circular permutation!
+    let count = match n {
+        0 => 0,
+        n => (1..n).product(),
+    };
+    Ok(table
+        .nodes()
+        .permutations(n)
+        .take(count)
+        .map(move |guests| {
+            let next = guests.iter().cycle().skip(1);
+            let xs = guests.iter().zip(next).map(|(&l, &r)| happiness[l][r]);
+
+            let total = xs.clone().sum::<i32>();
+            let min = xs.min().unwrap_or(0);
+
+            let guests = guests.into_iter().map(|guest| table.name(guest)).collect();
+            (guests, total, min)
+        }))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        let lines = input.read_lines()?;
+        let table = table(&lines)?;
+        let best = arrangements(&table)?.map(|(_, total, _)| total).max();
+        Ok(best.ok_or("nobody to sit at the table")?.to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let lines = input.read_lines()?;
+        let table = table(&lines)?;
+        // the inclusion of the host will break the weakest link
+        let best = arrangements(&table)?
+            .map(|(_, total, min)| total - min)
+            .max();
+        Ok(best.ok_or("nobody to sit at the table")?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn test_parse_pairing() {
+        let (_, pairing) =
+            parse_pairing("Alice would lose 79 happiness units by sitting next to Carol.").unwrap();
+        assert_eq!(pairing.first, "Alice");
+        assert_eq!(pairing.second, "Carol");
+        assert_eq!(pairing.gain, -79);
+    }
+
+    #[test]
+    fn test_sample_table() {
+        let input = Input::embedded(SAMPLE);
+        assert_eq!(Puzzle::part_one(&input).unwrap(), "330");
+    }
+
+    #[test]
+    fn test_incomplete_table() {
+        let error = |lines: &str| Puzzle::part_one(&Input::embedded(lines)).unwrap_err();
+        assert_eq!(error("").to_string(), "nobody to sit at the table");
+        let lines = SAMPLE.lines().skip(1).collect::<Vec<_>>().join("\n");
+        assert_eq!(
+            error(&lines).to_string(),
+            "Missing pairing: Alice next to Bob"
+        );
+
+        let alone = "Alice would gain 54 happiness units by sitting next to Alice.";
+        assert_eq!(Puzzle::part_one(&Input::embedded(alone)).unwrap(), "0");
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::{BinaryHeap, HashMap};
 use std::sync::Arc;
 
+use aoc_common::{Answer, Input, Solution};
+
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::space0;
@@ -77,7 +79,7 @@ pub fn parse_line(input: &str) -> IResult<&str, Reindeer> {
     ))
 }
 
-pub fn race_1(reindeers: &Vec<Reindeer>, t: usize) -> (&Reindeer, usize) {
+pub fn race_1(reindeers: &[Reindeer], t: usize) -> (&Reindeer, usize) {
     let winner = reindeers
         .iter()
         .map(|reindeer| (reindeer.distance_at(t), reindeer))
@@ -89,11 +91,11 @@ pub fn race_1(reindeers: &Vec<Reindeer>, t: usize) -> (&Reindeer, usize) {
 }
 
 struct Race<'a> {
-    reindeers: &'a Vec<Reindeer>,
+    reindeers: &'a [Reindeer],
 }
 
 impl<'a> Race<'a> {
-    pub fn top<const K: usize>(&self) -> Top<K> {
+    pub fn top<const K: usize>(&self) -> Top<'_, K> {
         Top::<K> {
             reindeers: self.reindeers,
             t: 0,
@@ -102,7 +104,7 @@ impl<'a> Race<'a> {
 }
 
 struct Top<'a, const K: usize> {
-    reindeers: &'a Vec<Reindeer>,
+    reindeers: &'a [Reindeer],
     t: usize,
 }
 
@@ -142,12 +144,11 @@ impl<'a, const K: usize> Iterator for Top<'a, K> {
     }
 }
 
-pub fn race_2(reindeers: &Vec<Reindeer>, t: usize) -> (Vec<String>, usize) {
-    let mut score_board: HashMap<&Reindeer, usize> =
-        reindeers.into_iter().map(|r| (r, 0)).collect();
+pub fn race_2(reindeers: &[Reindeer], t: usize) -> (Vec<String>, usize) {
+    let mut score_board: HashMap<&Reindeer, usize> = reindeers.iter().map(|r| (r, 0)).collect();
 
     let race = Race { reindeers };
-    for (_i, [(_distance, first), (_, second), (_, third)]) in race.top::<3>().take(t).enumerate() {
+    for [(_distance, first), (_, second), (_, third)] in race.top::<3>().take(t) {
         for f in first {
             // println!("\tt = {}: {} @ {}", i + 1, f.name, distance);
             score_board.entry(f).and_modify(|score| *score += 1);
@@ -169,3 +170,33 @@ pub fn race_2(reindeers: &Vec<Reindeer>, t: usize) -> (Vec<String>, usize) {
 
     (winners, max_score)
 }
+
+/// Length of the race in the puzzle, in seconds.
+pub const DURATION: usize = 2503;
+
+pub fn parse_reindeers(lines: &[String]) -> Result<Vec<Reindeer>, String> {
+    lines
+        .iter()
+        .map(|line| {
+            parse_line(line)
+                .map(|(_, reindeer)| reindeer)
+                .map_err(|_| format!("Invalid reindeer: {}", line))
+        })
+        .collect()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        let reindeers = parse_reindeers(&input.read_lines()?)?;
+        let (_, distance) = race_1(&reindeers, DURATION);
+        Ok(distance.to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let reindeers = parse_reindeers(&input.read_lines()?)?;
+        let (_, score) = race_2(&reindeers, DURATION);
+        Ok(score.to_string())
+    }
+}
@@ -2,8 +2,8 @@ use reindeer_olympics::{parse_line, race_1, race_2, Reindeer};
 
 mod io;
 
-fn main() {
-    let reindeers = io::lines()
+fn main() -> std::io::Result<()> {
+    let reindeers = aoc_common::lines()?
         .into_iter()
         .map(|line| {
             let (_, reindeer) = parse_line(&line).unwrap();
@@ -24,4 +24,5 @@ fn main() {
         "[{:?}] are the winners of part 2 with {} points.",
         winners, score
     );
+    Ok(())
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use aoc_common::{Answer, Input, Solution};
 use log::debug;
 
 #[derive(Debug, PartialEq)]
@@ -42,10 +43,10 @@ pub struct Score {
 }
 
 pub fn maximum_score(
-    ingredients: &Vec<Ingredient>,
+    ingredients: &[Ingredient],
     teaspoons: usize,
     calories: usize,
-) -> (Score, Vec<Amount>) {
+) -> (Score, Vec<Amount<'_>>) {
     n_multichoose_k(teaspoons, ingredients.len())
         .iter()
         .map(|composition| {
@@ -148,10 +149,59 @@ pub fn n_multichoose_k(n: usize, k: usize) -> Vec<Vec<usize>> {
     _multisubsets(n, k, &mut memo)
 }
 
+pub fn parse_line(line: String) -> Ingredient {
+    let parts: Vec<_> = line.split(':').collect();
+    let name = parts[0].trim().to_string();
+    let properties: HashMap<&str, i64> = parts[1]
+        .split(',')
+        .map(|v| {
+            let kv = v.trim().split(' ').collect::<Vec<_>>();
+            (kv[0].trim(), kv[1].trim().parse::<i64>().unwrap())
+        })
+        .collect();
+
+    Ingredient {
+        name,
+        capacity: properties["capacity"],
+        durability: properties["durability"],
+        flavor: properties["flavor"],
+        texture: properties["texture"],
+        calories: properties["calories"] as usize,
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_two(input: &Input) -> Answer {
+        let ingredients: Vec<Ingredient> =
+            input.read_lines()?.into_iter().map(parse_line).collect();
+        let (max_score, _) = maximum_score(&ingredients, 100, 500);
+        Ok(max_score.value.to_string())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_line_parser() {
+        assert_eq!(
+            parse_line(
+                "Sprinkles: capacity 2, durability 0, flavor -2, texture 0, calories 3".to_string(),
+            ),
+            Ingredient {
+                name: "Sprinkles".to_string(),
+                capacity: 2,
+                durability: 0,
+                flavor: -2,
+                texture: 0,
+                calories: 3
+            }
+        )
+    }
+
     #[test]
     fn test_f() {
         assert_eq!(n_multichoose_k(0, 1), [[0]]);
@@ -1,12 +1,12 @@
 use recipe::maximum_score;
-use recipe::Ingredient;
+use recipe::{parse_line, Ingredient};
 
 mod io;
 
-fn main() {
+fn main() -> std::io::Result<()> {
     env_logger::init();
 
-    let ingredients: Vec<Ingredient> = io::lines().map(io::parse_line).collect();
+    let ingredients: Vec<Ingredient> = aoc_common::lines()?.into_iter().map(parse_line).collect();
     let (max_score, amounts) = maximum_score(&ingredients, cli_arg!(2, "100"), cli_arg!(3, "500"));
 
     amounts.iter().for_each(|amount| {
@@ -14,4 +14,5 @@ fn main() {
     });
 
     println!("{:?}", max_score);
+    Ok(())
 }
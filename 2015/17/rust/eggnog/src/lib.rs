@@ -0,0 +1,75 @@
+use aoc_common::{Answer, Input, Solution};
+use itertools::Itertools;
+
+fn container_groups_that_add_to(
+    containers: &[usize],
+    volume: usize,
+) -> impl Iterator<Item = Vec<usize>> + '_ {
+    containers
+        .iter()
+        .copied()
+        .powerset()
+        .filter(move |cs| cs.iter().sum::<usize>() == volume)
+}
+
+pub fn number_of_container_groups_that_add_to_volume(containers: &[usize], volume: usize) -> usize {
+    container_groups_that_add_to(containers, volume).count()
+}
+
+pub fn number_of_smallest_container_groups_that_add_to_volume(
+    containers: &[usize],
+    volume: usize,
+) -> usize {
+    let groups: Vec<Vec<usize>> = container_groups_that_add_to(containers, volume).collect();
+    let smallest_size = groups
+        .iter()
+        .min_by_key(|group| group.len())
+        .expect("found no groups")
+        .len();
+
+    groups
+        .iter()
+        .filter(|group| group.len() == smallest_size)
+        .count()
+}
+
+pub fn parse_containers(input: &str) -> Result<Vec<usize>, std::num::ParseIntError> {
+    input.split_whitespace().map(str::parse).collect()
+}
+
+/// Liters of eggnog that need storing.
+pub const VOLUME: usize = 150;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const INPUT: Option<&'static str> = Some(include_str!("../input"));
+
+    fn part_one(input: &Input) -> Answer {
+        let containers = parse_containers(&input.read_to_string()?)?;
+        Ok(number_of_container_groups_that_add_to_volume(&containers, VOLUME).to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let containers = parse_containers(&input.read_to_string()?)?;
+        Ok(number_of_smallest_container_groups_that_add_to_volume(&containers, VOLUME).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            number_of_container_groups_that_add_to_volume(&[20, 15, 10, 5, 5], 25),
+            4
+        );
+
+        assert_eq!(
+            number_of_smallest_container_groups_that_add_to_volume(&[20, 15, 10, 5, 5], 25),
+            3
+        );
+    }
+}
@@ -41,14 +41,11 @@ impl<'a> Iterator for MoleculeIter<'a> {
                 .enumerate()
                 .flat_map(|(i, atom)| {
                     self.transition_map.get(atom).map(|transitions| {
-                        transitions
-                            .iter()
-                            .map(move |transition| {
-                                let mut nxt = curr.atoms.clone();
-                                let _ = nxt.splice(i..i + 1, transition.atoms.clone());
-                                Molecule { atoms: nxt }
-                            })
-                            .into_iter()
+                        transitions.iter().map(move |transition| {
+                            let mut nxt = curr.atoms.clone();
+                            let _ = nxt.splice(i..i + 1, transition.atoms.clone());
+                            Molecule { atoms: nxt }
+                        })
                     })
                 })
                 .flatten()
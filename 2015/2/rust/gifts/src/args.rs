@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use clap::Parser;
+use gifts::{Format, Rule};
 
 /// Simple program to calculate the amount of gift wrapping paper needed by Santa's elves
 #[derive(Parser, Debug)]
@@ -8,4 +9,20 @@ use clap::Parser;
 pub struct Args {
     /// File containing the gifts dimensions
     pub input: PathBuf,
+
+    /// Abort on the first line that can't be parsed instead of skipping it
+    #[arg(long)]
+    pub strict: bool,
+
+    /// How to print the itemized order
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    pub format: Format,
+
+    /// How to wrap the gifts
+    #[arg(long, value_enum, default_value_t = Rule::Standard)]
+    pub rule: Rule,
+
+    /// Minimum feet of ribbon for each gift, not counting the bow
+    #[arg(long, default_value_t = 0)]
+    pub min_ribbon: u64,
 }
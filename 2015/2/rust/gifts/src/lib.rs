@@ -1,82 +1,309 @@
+use std::error::Error;
+use std::fmt;
 use std::iter::Sum;
+use std::ops::Add;
 
-#[derive(Debug)]
-pub struct GiftBox {
-    pub height: u32,
-    pub length: u32,
-    pub width: u32,
+use aoc_common::{Answer, Input, Solution};
+use serde::Serialize;
+
+mod packaging;
+mod report;
+pub use packaging::{
+    Cylinder, LargestFaceSlack, Materials, MinimumRibbon, PackagingRule, Rule, Standard,
+};
+pub use report::{Format, Item, Report};
+
+/// A box with `N` dimensions, 3 being the usual kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GiftBox<const N: usize = 3> {
+    pub dimensions: [u32; N],
 }
 
-impl GiftBox {
+impl<const N: usize> GiftBox<N> {
+    /// Fails to compile for boxes with fewer than two dimensions, once evaluated.
+    const AT_LEAST_TWO_DIMENSIONS: () = assert!(N >= 2, "gift boxes need at least two dimensions");
+
+    /// Total area of the box's faces, each face being spanned by all dimensions but one.
     pub fn area(&self) -> u32 {
-        2 * (self.height * self.width + self.height * self.length + self.length * self.width)
+        2 * self.faces().sum::<u32>()
     }
 
     pub fn volume(&self) -> u32 {
-        return self.height * self.length * self.width
+        self.dimensions.iter().product()
     }
 
-    pub fn sorted_dimensions(&self) -> [u32; 3] {
-        let mut ordered = [self.height, self.length, self.width];
+    pub fn sorted_dimensions(&self) -> [u32; N] {
+        let mut ordered = self.dimensions;
         ordered.sort();
 
         ordered
     }
+
+    /// Area of the face spanned by all dimensions but the largest one.
+    pub fn smallest_face(&self) -> u32 {
+        self.sorted_dimensions()[..N - 1].iter().product()
+    }
+
+    /// Area of the face spanned by all dimensions but the smallest one.
+    pub fn largest_face(&self) -> u32 {
+        self.sorted_dimensions()[1..].iter().product()
+    }
+
+    /// Smallest perimeter of any one face, i.e. around the two smallest dimensions.
+    pub fn smallest_perimeter(&self) -> u32 {
+        let () = Self::AT_LEAST_TWO_DIMENSIONS;
+        let sorted = self.sorted_dimensions();
+        2 * (sorted[0] + sorted[1])
+    }
+
+    fn faces(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..N).map(move |i| {
+            self.dimensions
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, d)| d)
+                .product()
+        })
+    }
+}
+
+impl<const N: usize> fmt::Display for GiftBox<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let dimensions: Vec<String> = self.dimensions.iter().map(u32::to_string).collect();
+        write!(f, "{}", dimensions.join("x"))
+    }
 }
 
-#[derive(Debug)]
+/// Totals are kept as `u64` so that adding up many boxes can't overflow.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
 pub struct Order {
-    pub wrapping_paper: u32,
-    pub ribbon: u32,
+    pub wrapping_paper: u64,
+    pub ribbon: u64,
 }
 
 impl Order {
     pub fn from_gift_box(b: &GiftBox) -> Order {
+        Order::from_gift(&Standard, b)
+    }
+
+    /// What it takes to wrap `gift` according to `rule`.
+    pub fn from_gift<R: PackagingRule<N>, const N: usize>(rule: &R, gift: &GiftBox<N>) -> Order {
+        Order::from(rule.materials(gift))
+    }
+}
+
+impl From<Materials> for Order {
+    fn from(m: Materials) -> Order {
         Order {
-            wrapping_paper: paper_needed(b),
-            ribbon: ribbon_needed(b),
+            wrapping_paper: m.paper + m.slack,
+            ribbon: m.ribbon + m.bow,
+        }
+    }
+}
+
+impl Add for Order {
+    type Output = Order;
+
+    fn add(self, other: Order) -> Order {
+        Order {
+            wrapping_paper: self.wrapping_paper + other.wrapping_paper,
+            ribbon: self.ribbon + other.ribbon,
         }
     }
 }
 
 impl Sum for Order {
-    fn sum<I: Iterator<Item=Order>>(iter: I) -> Order {
-        iter.fold(Order {wrapping_paper: 0, ribbon: 0}, |acc, curr| Order {
-            wrapping_paper: acc.wrapping_paper + curr.wrapping_paper,
-            ribbon: acc.ribbon + curr.ribbon,
-        })
+    fn sum<I: Iterator<Item = Order>>(iter: I) -> Order {
+        iter.fold(Order::default(), Add::add)
     }
 }
 
-pub fn line_to_box(line: &str) -> Result<GiftBox, String> {
-    let parse = |s: &str| s.parse();
-    let dimensions: Result<Vec<u32>, _> = line.split("x").map(parse).collect();
-    match dimensions {
-        Err(err) => Err(format!("Found invalid dimension: {}", err)),
-        Ok(dimensions) => {
-            if dimensions.len() != 3 {
-                return Err(format!("Expected line to contain 3 dimensions"));
+/// Why a line could not be turned into a [`GiftBox`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseErrorReason {
+    InvalidDimension(String),
+    ZeroDimension,
+    WrongDimensionCount {
+        expected: usize,
+        found: usize,
+    },
+    /// The paper or ribbon needed for the box does not fit in a `u32`.
+    Overflow,
+}
+
+impl fmt::Display for ParseErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorReason::InvalidDimension(s) => write!(f, "invalid dimension {:?}", s),
+            ParseErrorReason::ZeroDimension => write!(f, "dimensions must be greater than zero"),
+            ParseErrorReason::WrongDimensionCount { expected, found } => {
+                write!(f, "expected {} dimensions but found {}", expected, found)
             }
-            Ok(GiftBox {
-                height: dimensions[0],
-                length: dimensions[1],
-                width: dimensions[2],
-            })
+            ParseErrorReason::Overflow => write!(f, "box is too large"),
         }
     }
 }
 
-pub fn paper_needed(b: &GiftBox) -> u32 {
-    let [smallest, second_smallest, ..] = b.sorted_dimensions();
+/// A line of the input that does not describe a gift box. Line and column are 1-based.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseGiftBoxError {
+    pub line: usize,
+    pub column: usize,
+    pub reason: ParseErrorReason,
+}
 
-    b.area() + smallest * second_smallest
+impl fmt::Display for ParseGiftBoxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )
+    }
 }
 
-pub fn ribbon_needed(b: &GiftBox) -> u32 {
-    let [smallest, second_smallest, ..] = b.sorted_dimensions();
-    return 2 * (smallest + second_smallest) + b.volume()
+impl Error for ParseGiftBoxError {}
+
+/// Parses a single line of dimensions, as if it were the first line of the input.
+pub fn line_to_box(line: &str) -> Result<GiftBox, ParseGiftBoxError> {
+    parse_line(1, line)
 }
- 
+
+/// Parses the `number`th line of the input into a gift box.
+///
+/// # Example
+/// ```rust
+/// use gifts::{parse_line, ParseErrorReason};
+///
+/// assert_eq!(parse_line(1, "2x3x4").unwrap().volume(), 24);
+///
+/// let err = parse_line(7, "2x0x4").unwrap_err();
+/// assert_eq!((err.line, err.column), (7, 3));
+/// assert_eq!(err.reason, ParseErrorReason::ZeroDimension);
+/// ```
+pub fn parse_line(number: usize, line: &str) -> Result<GiftBox, ParseGiftBoxError> {
+    parse_dimensions(number, line)
+}
+
+/// Parses the `number`th line of the input into a box with `N` dimensions.
+///
+/// # Example
+/// ```rust
+/// use gifts::{parse_dimensions, GiftBox};
+///
+/// let b: GiftBox<4> = parse_dimensions(1, "1x2x3x4").unwrap();
+/// assert_eq!(b.volume(), 24);
+/// assert!(parse_dimensions::<2>(1, "1x2x3").is_err());
+/// ```
+pub fn parse_dimensions<const N: usize>(
+    number: usize,
+    line: &str,
+) -> Result<GiftBox<N>, ParseGiftBoxError> {
+    let () = GiftBox::<N>::AT_LEAST_TWO_DIMENSIONS;
+    let error = |column, reason| ParseGiftBoxError {
+        line: number,
+        column,
+        reason,
+    };
+
+    let mut dimensions = [0; N];
+    let mut count = 0;
+    let mut column = 1;
+    for token in line.split('x') {
+        if count == N {
+            let reason = ParseErrorReason::WrongDimensionCount {
+                expected: N,
+                found: line.split('x').count(),
+            };
+            return Err(error(column, reason));
+        }
+        let dimension = token.parse::<u32>().map_err(|_| {
+            error(
+                column,
+                ParseErrorReason::InvalidDimension(token.to_string()),
+            )
+        })?;
+        if dimension == 0 {
+            return Err(error(column, ParseErrorReason::ZeroDimension));
+        }
+        dimensions[count] = dimension;
+        count += 1;
+        column += token.len() + 1;
+    }
+
+    if count < N {
+        let reason = ParseErrorReason::WrongDimensionCount {
+            expected: N,
+            found: count,
+        };
+        return Err(error(line.len() + 1, reason));
+    }
+
+    let b = GiftBox { dimensions };
+    if checked_paper_needed(&b).is_none() || checked_ribbon_needed(&b).is_none() {
+        return Err(error(1, ParseErrorReason::Overflow));
+    }
+    Ok(b)
+}
+
+fn checked_product(dimensions: &[u32]) -> Option<u32> {
+    dimensions
+        .iter()
+        .try_fold(1u32, |acc, &d| acc.checked_mul(d))
+}
+
+fn checked_paper_needed<const N: usize>(b: &GiftBox<N>) -> Option<u32> {
+    let sorted = b.sorted_dimensions();
+    let mut paper = checked_product(&sorted[..N - 1])?;
+    for i in 0..N {
+        let mut others = sorted.to_vec();
+        others.remove(i);
+        paper = paper.checked_add(checked_product(&others)?.checked_mul(2)?)?;
+    }
+    Some(paper)
+}
+
+fn checked_ribbon_needed<const N: usize>(b: &GiftBox<N>) -> Option<u32> {
+    let sorted = b.sorted_dimensions();
+    sorted[0]
+        .checked_add(sorted[1])?
+        .checked_mul(2)?
+        .checked_add(checked_product(&sorted)?)
+}
+
+pub fn paper_needed<const N: usize>(b: &GiftBox<N>) -> u32 {
+    b.area() + b.smallest_face()
+}
+
+pub fn ribbon_needed<const N: usize>(b: &GiftBox<N>) -> u32 {
+    b.smallest_perimeter() + b.volume()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        let order = total_order(input)?;
+        Ok(order.wrapping_paper.to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let order = total_order(input)?;
+        Ok(order.ribbon.to_string())
+    }
+}
+
+fn total_order(input: &Input) -> Result<Order, Box<dyn Error>> {
+    let order = input
+        .read_lines()?
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(i + 1, line).map(|b| Order::from_gift_box(&b)))
+        .sum::<Result<Order, ParseGiftBoxError>>()?;
+    Ok(order)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,7 +320,7 @@ mod tests {
         let line = "2x3x4";
         let b = line_to_box(line).unwrap();
         assert_eq!(b.volume(), 24);
-    }    
+    }
 
     #[test]
     fn wrapping_paper_area() {
@@ -115,4 +342,65 @@ mod tests {
         let b = line_to_box(line).unwrap();
         assert_eq!(ribbon_needed(&b), 14);
     }
+
+    #[test]
+    fn parse_errors() {
+        let reason = |line| parse_line(1, line).unwrap_err().reason;
+        let column = |line| parse_line(1, line).unwrap_err().column;
+
+        assert_eq!(
+            reason("2xax4"),
+            ParseErrorReason::InvalidDimension("a".to_string())
+        );
+        assert_eq!(column("2xax4"), 3);
+        assert_eq!(
+            reason(""),
+            ParseErrorReason::InvalidDimension("".to_string())
+        );
+        assert_eq!(
+            reason("2x-3x4"),
+            ParseErrorReason::InvalidDimension("-3".to_string())
+        );
+        assert_eq!(reason("2x3x0"), ParseErrorReason::ZeroDimension);
+        assert_eq!(column("2x3x0"), 5);
+        assert_eq!(
+            reason("2x3"),
+            ParseErrorReason::WrongDimensionCount {
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(column("2x3"), 4);
+        assert_eq!(
+            reason("2x3x4x5x6"),
+            ParseErrorReason::WrongDimensionCount {
+                expected: 3,
+                found: 5
+            }
+        );
+        assert_eq!(column("2x3x4x5x6"), 7);
+    }
+
+    #[test]
+    fn parse_overflow() {
+        assert_eq!(
+            parse_line(1, "65536x65536x1").unwrap_err().reason,
+            ParseErrorReason::Overflow
+        );
+        assert_eq!(
+            parse_line(1, "2000x2000x2000").unwrap_err().reason,
+            ParseErrorReason::Overflow
+        );
+        assert!(parse_line(1, "1000x1000x1000").is_ok());
+    }
+
+    #[test]
+    fn total_order_reports_line() {
+        let input = Input::embedded("2x3x4\n1x1x10\n1x1\n");
+        let err = total_order(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 4: expected 3 dimensions but found 2"
+        );
+    }
 }
@@ -1,35 +1,64 @@
 mod args;
 
-use gifts::{line_to_box, Order, GiftBox};
+use std::error::Error;
+use std::process;
 
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use gifts::{
+    parse_dimensions, Cylinder, GiftBox, LargestFaceSlack, MinimumRibbon, PackagingRule,
+    ParseGiftBoxError, Report, Rule, Standard,
+};
 
+use aoc_common::{Input, Lines, Source};
 use args::Args;
 use clap::Parser;
 
-fn boxes(reader: BufReader<File>) -> impl Iterator<Item = Result<GiftBox, String>> {
-    reader.lines().map(|line| {
-        let l = line.map_err(|e| e.to_string())?;
-        line_to_box(&l)
-    })
+fn boxes<const N: usize>(
+    lines: Lines,
+) -> impl Iterator<Item = std::io::Result<Result<GiftBox<N>, ParseGiftBoxError>>> {
+    lines
+        .enumerate()
+        .map(|(i, line)| line.map(|l| parse_dimensions(i + 1, &l)))
 }
 
-fn main() {
-    let args = Args::parse();
-    let file = File::open(args.input).expect("Failed to open file");
-    let reader = BufReader::new(file);
-    let order: Order = boxes(reader)
-        .map(|b| match b {
-            Ok(b) => Order::from_gift_box(&b),
-            Err(s) => {
-                println!("Skipping line: {}", s);
-                Order {
-                    wrapping_paper: 0,
-                    ribbon: 0,
-                }
+fn order<R: PackagingRule<N>, const N: usize>(
+    args: &Args,
+    lines: Lines,
+    rule: R,
+) -> Result<(), Box<dyn Error>> {
+    let rule = MinimumRibbon {
+        rule,
+        minimum: args.min_ribbon,
+    };
+
+    let mut report = Report::default();
+    let (mut total, mut skipped) = (0, 0);
+    for (i, b) in boxes(lines).enumerate() {
+        total += 1;
+        match b? {
+            Ok(b) => report.push(i + 1, &rule, &b),
+            Err(e) if args.strict => {
+                eprintln!("Error: {}", e);
+                process::exit(1);
             }
-        })
-        .sum();
-    println!("order = {:?}", order);
+            Err(e) => {
+                eprintln!("Skipping {}", e);
+                skipped += 1;
+            }
+        }
+    }
+
+    report.write(args.format, std::io::stdout().lock())?;
+    eprintln!("skipped {} of {} lines", skipped, total);
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let lines = Input::new(Source::File(args.input.clone())).lines()?;
+
+    match args.rule {
+        Rule::Standard => order::<_, 3>(&args, lines, Standard),
+        Rule::LargestFaceSlack => order::<_, 3>(&args, lines, LargestFaceSlack),
+        Rule::Cylinder => order(&args, lines, Cylinder),
+    }
 }
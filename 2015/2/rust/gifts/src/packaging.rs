@@ -0,0 +1,202 @@
+use std::f64::consts::PI;
+
+use clap::{builder::PossibleValue, ValueEnum};
+use serde::Serialize;
+
+use crate::GiftBox;
+
+/// Materials needed to wrap a single gift.
+///
+/// The paper needed is `paper + slack` and the ribbon needed is `ribbon + bow`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Materials {
+    pub paper: u64,
+    pub slack: u64,
+    pub ribbon: u64,
+    pub bow: u64,
+}
+
+/// A policy for wrapping gifts with `N` dimensions.
+pub trait PackagingRule<const N: usize = 3> {
+    fn materials(&self, gift: &GiftBox<N>) -> Materials;
+}
+
+impl<R: PackagingRule<N>, const N: usize> PackagingRule<N> for &R {
+    fn materials(&self, gift: &GiftBox<N>) -> Materials {
+        (**self).materials(gift)
+    }
+}
+
+/// The elves' usual rule: the surface of the box plus its smallest face as slack, and a ribbon
+/// around the smallest perimeter with a bow as long as the volume.
+///
+/// # Example
+/// ```rust
+/// use gifts::{line_to_box, Order, Standard};
+///
+/// let order = Order::from_gift(&Standard, &line_to_box("2x3x4").unwrap());
+/// assert_eq!((order.wrapping_paper, order.ribbon), (58, 34));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Standard;
+
+impl<const N: usize> PackagingRule<N> for Standard {
+    fn materials(&self, gift: &GiftBox<N>) -> Materials {
+        Materials {
+            paper: gift.area() as u64,
+            slack: gift.smallest_face() as u64,
+            ribbon: gift.smallest_perimeter() as u64,
+            bow: gift.volume() as u64,
+        }
+    }
+}
+
+/// Like [`Standard`], but the slack is the largest face, the one spanned by the two largest
+/// dimensions of a regular box.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LargestFaceSlack;
+
+impl<const N: usize> PackagingRule<N> for LargestFaceSlack {
+    fn materials(&self, gift: &GiftBox<N>) -> Materials {
+        Materials {
+            slack: gift.largest_face() as u64,
+            ..Standard.materials(gift)
+        }
+    }
+}
+
+/// Wraps another rule so that no gift gets less than `minimum` feet of ribbon, bow excluded.
+///
+/// # Example
+/// ```rust
+/// use gifts::{line_to_box, MinimumRibbon, PackagingRule, Standard};
+///
+/// let rule = MinimumRibbon { rule: Standard, minimum: 12 };
+/// assert_eq!(rule.materials(&line_to_box("2x3x4").unwrap()).ribbon, 12);
+/// assert_eq!(rule.materials(&line_to_box("5x5x5").unwrap()).ribbon, 20);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinimumRibbon<R> {
+    pub rule: R,
+    pub minimum: u64,
+}
+
+impl<R: PackagingRule<N>, const N: usize> PackagingRule<N> for MinimumRibbon<R> {
+    fn materials(&self, gift: &GiftBox<N>) -> Materials {
+        let materials = self.rule.materials(gift);
+        Materials {
+            ribbon: materials.ribbon.max(self.minimum),
+            ..materials
+        }
+    }
+}
+
+/// Cylinder shaped gifts, given as `diameter x height`.
+///
+/// The side is rolled in paper and both ends are cut out of squares, with one more square as
+/// slack. The ribbon goes around the cylinder once and the bow is as long as its volume. Lengths
+/// are rounded up to whole feet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cylinder;
+
+impl PackagingRule<2> for Cylinder {
+    fn materials(&self, gift: &GiftBox<2>) -> Materials {
+        let [diameter, height] = gift.dimensions.map(u64::from);
+        let circumference = (PI * diameter as f64).ceil() as u64;
+        let square = diameter * diameter;
+        Materials {
+            paper: circumference * height + 2 * square,
+            slack: square,
+            ribbon: circumference,
+            bow: (PI * (square * height) as f64 / 4.0).ceil() as u64,
+        }
+    }
+}
+
+/// The rules that can be picked from the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    Standard,
+    LargestFaceSlack,
+    Cylinder,
+}
+
+impl ValueEnum for Rule {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Standard, Self::LargestFaceSlack, Self::Cylinder]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Standard => {
+                PossibleValue::new("standard").help("smallest face as slack, lines are LxWxH")
+            }
+            Self::LargestFaceSlack => {
+                PossibleValue::new("largest-face-slack").help("largest face as slack")
+            }
+            Self::Cylinder => PossibleValue::new("cylinder").help("lines are DIAMETERxHEIGHT"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{paper_needed, parse_dimensions, ribbon_needed, Order};
+
+    #[test]
+    fn standard_matches_puzzle() {
+        for line in ["2x3x4", "1x1x10", "7x3x5"] {
+            let b = parse_dimensions::<3>(1, line).unwrap();
+            let order = Order::from_gift(&Standard, &b);
+            assert_eq!(order.wrapping_paper, paper_needed(&b) as u64);
+            assert_eq!(order.ribbon, ribbon_needed(&b) as u64);
+        }
+    }
+
+    #[test]
+    fn standard_in_other_dimensions() {
+        let rectangle = parse_dimensions::<2>(1, "2x3").unwrap();
+        assert_eq!(
+            Standard.materials(&rectangle),
+            Materials {
+                paper: 10,
+                slack: 2,
+                ribbon: 10,
+                bow: 6
+            }
+        );
+
+        let tesseract = parse_dimensions::<4>(1, "1x2x3x4").unwrap();
+        assert_eq!(
+            Standard.materials(&tesseract),
+            Materials {
+                paper: 2 * (24 + 12 + 8 + 6),
+                slack: 6,
+                ribbon: 6,
+                bow: 24
+            }
+        );
+    }
+
+    #[test]
+    fn largest_face_slack() {
+        let b = parse_dimensions::<3>(1, "2x3x4").unwrap();
+        assert_eq!(LargestFaceSlack.materials(&b).slack, 12);
+        assert_eq!(Order::from_gift(&LargestFaceSlack, &b).wrapping_paper, 64);
+    }
+
+    #[test]
+    fn cylinder() {
+        let b = parse_dimensions::<2>(1, "2x10").unwrap();
+        assert_eq!(
+            Cylinder.materials(&b),
+            Materials {
+                paper: 7 * 10 + 8,
+                slack: 4,
+                ribbon: 7,
+                bow: 32
+            }
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::error::Error;
+use std::io::Write;
+
+use clap::{builder::PossibleValue, ValueEnum};
+use serde::Serialize;
+
+use crate::{GiftBox, Materials, Order, PackagingRule};
+
+const COLUMNS: [&str; 6] = ["line", "dimensions", "paper", "slack", "ribbon", "bow"];
+
+/// How much of each material a single box takes.
+///
+/// The paper needed is `paper + slack` and the ribbon needed is `ribbon + bow`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Item {
+    pub line: usize,
+    /// Dimensions as they appear in the input, e.g. `2x3x4`.
+    pub dimensions: String,
+    pub paper: u64,
+    pub slack: u64,
+    pub ribbon: u64,
+    pub bow: u64,
+}
+
+impl Item {
+    pub fn new<const N: usize>(line: usize, gift: &GiftBox<N>, materials: Materials) -> Item {
+        Item {
+            line,
+            dimensions: gift.to_string(),
+            paper: materials.paper,
+            slack: materials.slack,
+            ribbon: materials.ribbon,
+            bow: materials.bow,
+        }
+    }
+
+    fn cells(&self) -> [String; 6] {
+        [
+            self.line.to_string(),
+            self.dimensions.clone(),
+            self.paper.to_string(),
+            self.slack.to_string(),
+            self.ribbon.to_string(),
+            self.bow.to_string(),
+        ]
+    }
+}
+
+/// Every gift in the order, along with the totals.
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    pub items: Vec<Item>,
+    pub total: Order,
+}
+
+impl Report {
+    /// Adds the `line`th gift of the input, wrapped according to `rule`.
+    pub fn push<R: PackagingRule<N>, const N: usize>(
+        &mut self,
+        line: usize,
+        rule: &R,
+        gift: &GiftBox<N>,
+    ) {
+        let materials = rule.materials(gift);
+        self.items.push(Item::new(line, gift, materials));
+        self.total = std::mem::take(&mut self.total) + Order::from(materials);
+    }
+
+    /// Sums of the paper, slack, ribbon and bow columns.
+    fn column_totals(&self) -> [u64; 4] {
+        self.items.iter().fold([0; 4], |acc, item| {
+            [
+                acc[0] + item.paper,
+                acc[1] + item.slack,
+                acc[2] + item.ribbon,
+                acc[3] + item.bow,
+            ]
+        })
+    }
+
+    pub fn write(&self, format: Format, mut out: impl Write) -> Result<(), Box<dyn Error>> {
+        match format {
+            Format::Table => self.write_table(out)?,
+            Format::Csv => self.write_csv(out)?,
+            Format::Json => {
+                serde_json::to_writer_pretty(&mut out, self)?;
+                writeln!(out)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_table(&self, mut out: impl Write) -> std::io::Result<()> {
+        let mut rows: Vec<[String; 6]> = self.items.iter().map(Item::cells).collect();
+        let [paper, slack, ribbon, bow] = self.column_totals().map(|n| n.to_string());
+        rows.push([
+            "total".to_string(),
+            String::new(),
+            paper,
+            slack,
+            ribbon,
+            bow,
+        ]);
+
+        let mut widths = COLUMNS.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let header = COLUMNS.map(String::from);
+        for row in std::iter::once(&header).chain(&rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:>width$}", cell))
+                .collect();
+            writeln!(out, "{}", cells.join("  "))?;
+        }
+        writeln!(
+            out,
+            "\nwrapping paper = {}, ribbon = {}",
+            self.total.wrapping_paper, self.total.ribbon
+        )
+    }
+
+    fn write_csv(&self, out: impl Write) -> csv::Result<()> {
+        let mut writer = csv::Writer::from_writer(out);
+        for item in &self.items {
+            writer.serialize(item)?;
+        }
+        if self.items.is_empty() {
+            writer.write_record(COLUMNS)?;
+        }
+        let [paper, slack, ribbon, bow] = self.column_totals().map(|n| n.to_string());
+        writer.write_record(["total", "", &paper, &slack, &ribbon, &bow])?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Output format of a [`Report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+impl ValueEnum for Format {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Table, Self::Csv, Self::Json]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Table => PossibleValue::new("table").help("aligned columns, for humans"),
+            Self::Csv => PossibleValue::new("csv"),
+            Self::Json => PossibleValue::new("json"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_to_box, Standard};
+
+    fn report() -> Report {
+        let mut report = Report::default();
+        report.push(1, &Standard, &line_to_box("2x3x4").unwrap());
+        report.push(3, &Standard, &line_to_box("1x1x10").unwrap());
+        report
+    }
+
+    #[test]
+    fn totals() {
+        let report = report();
+        assert_eq!(
+            report.total,
+            Order {
+                wrapping_paper: 58 + 43,
+                ribbon: 34 + 14
+            }
+        );
+        let [paper, slack, ribbon, bow] = report.column_totals();
+        assert_eq!(paper + slack, report.total.wrapping_paper);
+        assert_eq!(ribbon + bow, report.total.ribbon);
+    }
+
+    #[test]
+    fn csv() {
+        let mut out = Vec::new();
+        report().write(Format::Csv, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "line,dimensions,paper,slack,ribbon,bow\n\
+             1,2x3x4,52,6,10,24\n\
+             3,1x1x10,42,1,4,10\n\
+             total,,94,7,14,34\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let mut out = Vec::new();
+        report().write(Format::Json, &mut out).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value["items"][1]["line"], 3);
+        assert_eq!(value["items"][1]["dimensions"], "1x1x10");
+        assert_eq!(value["items"][0]["slack"], 6);
+        assert_eq!(value["total"]["wrapping_paper"], 101);
+        assert_eq!(value["total"]["ribbon"], 48);
+    }
+}
@@ -0,0 +1,271 @@
+use aoc_common::{Answer, Input, Solution};
+use itertools::iproduct;
+
+#[derive(Clone, Debug)]
+pub struct Weapon {
+    pub name: String,
+    pub cost: usize,
+    pub damage: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Armor {
+    pub name: String,
+    pub cost: usize,
+    pub armor: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Ring {
+    pub name: String,
+    pub cost: usize,
+    pub damage: usize,
+    pub armor: usize,
+}
+
+#[derive(Clone, Debug)]
+pub enum RingSetup {
+    NoRing,
+    OneRing(Ring),
+    TwoRings(Ring, Ring),
+}
+
+#[derive(Debug)]
+pub struct Player {
+    pub hit_points: usize,
+    pub weapon: Weapon,
+    pub armor: Option<Armor>,
+    pub rings: RingSetup,
+}
+
+impl Player {
+    pub fn cost(&self) -> usize {
+        let armor = self.armor.as_ref();
+        self.weapon.cost
+            + armor.map_or(0, |a| a.cost)
+            + match &self.rings {
+                RingSetup::NoRing => 0,
+                RingSetup::OneRing(ring) => ring.cost,
+                RingSetup::TwoRings(left, right) => left.cost + right.cost,
+            }
+    }
+}
+
+#[derive(Debug)]
+pub struct Boss {
+    pub hit_points: usize,
+    pub damage: usize,
+    pub armor: usize,
+}
+
+/// Everything the item shop has for sale.
+pub fn shop() -> (Vec<Weapon>, Vec<Armor>, Vec<Ring>) {
+    let weapons = vec![
+        Weapon {
+            name: "Dagger".to_owned(),
+            cost: 8,
+            damage: 4,
+        },
+        Weapon {
+            name: "Shortsword".to_owned(),
+            cost: 10,
+            damage: 5,
+        },
+        Weapon {
+            name: "Warhammer".to_owned(),
+            cost: 25,
+            damage: 6,
+        },
+        Weapon {
+            name: "Longsword".to_owned(),
+            cost: 40,
+            damage: 7,
+        },
+        Weapon {
+            name: "Greataxe".to_owned(),
+            cost: 74,
+            damage: 8,
+        },
+    ];
+    let armors = vec![
+        Armor {
+            name: "Leather".to_owned(),
+            cost: 13,
+            armor: 1,
+        },
+        Armor {
+            name: "Chainmail".to_owned(),
+            cost: 31,
+            armor: 2,
+        },
+        Armor {
+            name: "Splintmail".to_owned(),
+            cost: 53,
+            armor: 3,
+        },
+        Armor {
+            name: "Bandedmail".to_owned(),
+            cost: 75,
+            armor: 4,
+        },
+        Armor {
+            name: "Platemail".to_owned(),
+            cost: 102,
+            armor: 5,
+        },
+    ];
+    let rings = vec![
+        Ring {
+            name: "Damage +1".to_owned(),
+            cost: 25,
+            damage: 1,
+            armor: 0,
+        },
+        Ring {
+            name: "Damage +2".to_owned(),
+            cost: 50,
+            damage: 2,
+            armor: 0,
+        },
+        Ring {
+            name: "Damage +3".to_owned(),
+            cost: 100,
+            damage: 3,
+            armor: 0,
+        },
+        Ring {
+            name: "Defense +1".to_owned(),
+            cost: 20,
+            damage: 0,
+            armor: 1,
+        },
+        Ring {
+            name: "Defense +2".to_owned(),
+            cost: 40,
+            damage: 0,
+            armor: 2,
+        },
+        Ring {
+            name: "Defense +3".to_owned(),
+            cost: 80,
+            damage: 0,
+            armor: 3,
+        },
+    ];
+
+    (weapons, armors, rings)
+}
+
+/// Every player that can be equipped from the shop: one weapon, at most one armor and at most
+/// two different rings.
+pub fn players<'a>(
+    weapons: &'a [Weapon],
+    armors: &'a [Armor],
+    rings: &'a [Ring],
+) -> impl Iterator<Item = Player> + 'a {
+    iproduct!(weapons.iter(), armors.iter()).flat_map(move |(weapon, armor)| {
+        let armor_combinations = vec![None, Some(armor.clone())];
+
+        let ring_combinations = vec![RingSetup::NoRing]
+            .into_iter()
+            .chain(rings.iter().map(|r| RingSetup::OneRing(r.clone())))
+            .chain(
+                iproduct!(rings.iter().enumerate(), rings.iter().enumerate())
+                    .filter(|((i, _), (j, _))| i < j)
+                    .map(|((_, left), (_, right))| {
+                        RingSetup::TwoRings(left.clone(), right.clone())
+                    }),
+            );
+
+        iproduct!(armor_combinations, ring_combinations).map(|(armor, rings)| Player {
+            hit_points: 100,
+            weapon: weapon.clone(),
+            armor,
+            rings,
+        })
+    })
+}
+
+/// Parses the boss stats as they are listed in the puzzle input.
+///
+/// # Example
+/// ```rust
+/// use rpg::parse_boss;
+///
+/// let boss = parse_boss("Hit Points: 109\nDamage: 8\nArmor: 2").unwrap();
+/// assert_eq!((boss.hit_points, boss.damage, boss.armor), (109, 8, 2));
+/// assert!(parse_boss("Hit Points: 109").is_none());
+/// ```
+pub fn parse_boss(input: &str) -> Option<Boss> {
+    let mut stats = input.lines().map(|line| {
+        let (_, value) = line.split_once(':')?;
+        value.trim().parse::<usize>().ok()
+    });
+
+    Some(Boss {
+        hit_points: stats.next()??,
+        damage: stats.next()??,
+        armor: stats.next()??,
+    })
+}
+
+pub struct Puzzle;
+
+impl Puzzle {
+    fn costs(input: &Input, win: bool) -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+        let boss = parse_boss(&input.read_to_string()?).ok_or("invalid boss stats")?;
+        let (weapons, armors, rings) = shop();
+        Ok(players(&weapons, &armors, &rings)
+            .filter(|player| can_beat(player, &boss) == win)
+            .map(|player| player.cost())
+            .collect())
+    }
+}
+
+impl Solution for Puzzle {
+    const INPUT: Option<&'static str> = Some("Hit Points: 109\nDamage: 8\nArmor: 2");
+
+    fn part_one(input: &Input) -> Answer {
+        let cheapest = Self::costs(input, true)?.into_iter().min();
+        Ok(cheapest.ok_or("no player can win")?.to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let priciest = Self::costs(input, false)?.into_iter().max();
+        Ok(priciest.ok_or("no player can lose")?.to_string())
+    }
+}
+
+pub fn can_beat(player: &Player, boss: &Boss) -> bool {
+    let player_armor = if let Some(a) = &player.armor {
+        a.armor
+    } else {
+        0_usize
+    };
+
+    let player_rings_damage = match &player.rings {
+        RingSetup::OneRing(ring) => ring.damage,
+        RingSetup::TwoRings(left, right) => left.damage + right.damage,
+        _ => 0,
+    };
+
+    let player_rings_armor = match &player.rings {
+        RingSetup::OneRing(ring) => ring.armor,
+        RingSetup::TwoRings(left, right) => left.armor + right.armor,
+        _ => 0,
+    };
+
+    let player_deal = std::cmp::max(
+        1,
+        player.weapon.damage as i64 + player_rings_damage as i64 - boss.armor as i64,
+    ) as usize;
+    let boss_deal = std::cmp::max(
+        1,
+        boss.damage as i64 - player_armor as i64 - player_rings_armor as i64,
+    ) as usize;
+
+    let player_required_turns = boss.hit_points / player_deal;
+    let boss_required_turns = player.hit_points / boss_deal;
+
+    player_required_turns <= boss_required_turns
+}
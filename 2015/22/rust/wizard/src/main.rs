@@ -138,8 +138,8 @@ fn main() {
 
         let spell_damage = node
             .active_spells
-            .iter()
-            .map(|(spell, _)| spell.damage)
+            .keys()
+            .map(|spell| spell.damage)
             .sum::<usize>();
 
         if node.boss.hit_points <= spell_damage {
@@ -179,7 +179,7 @@ fn main() {
                         mana: node.player.mana,
                     },
                     boss: Boss {
-                        hit_points: node.boss.hit_points - 0usize,
+                        hit_points: node.boss.hit_points,
                         damage: node.boss.damage,
                     },
                     active_spells: decremented_active_spells,
@@ -194,7 +194,7 @@ fn main() {
                     .filter(|spell| spell.cost <= node.player.mana)
                     .collect();
 
-                if available_spells.len() == 0 {
+                if available_spells.is_empty() {
                     // player can't cast a spell, end of the line.
                     println!("player can't cast a spell... try other timeline");
                     continue;
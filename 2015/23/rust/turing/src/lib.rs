@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+use aoc_common::{Answer, Input, Solution};
+
+pub type Registers = [u64; 2];
+pub type Register = u64;
+
+pub type Offset = i64;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub enum Instruction {
+    HLF(Register),
+    TPL(Register),
+    INC(Register),
+    JMP(Offset),
+    JIE(Register, Offset),
+    JIO(Register, Offset),
+}
+
+#[derive(Debug)]
+pub struct InstructionParsingError(pub String);
+
+impl FromStr for Instruction {
+    type Err = InstructionParsingError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split(' ').collect::<Vec<&str>>();
+        let instruction = match words[0] {
+            "hlf" => {
+                let register = words[1].chars().next().unwrap() as Register - 'a' as Register;
+                Instruction::HLF(register)
+            }
+            "tpl" => {
+                let register = words[1].chars().next().unwrap() as Register - 'a' as Register;
+                Instruction::TPL(register)
+            }
+            "inc" => {
+                let register = words[1].chars().next().unwrap() as Register - 'a' as Register;
+                Instruction::INC(register)
+            }
+            "jmp" => {
+                let offset = words[1].parse::<Offset>().unwrap();
+                Instruction::JMP(offset)
+            }
+            "jie" => {
+                let register = words[1].chars().next().unwrap() as Register - 'a' as Register;
+                let offset = words[2].parse::<Offset>().unwrap();
+                Instruction::JIE(register, offset)
+            }
+            "jio" => {
+                let register = words[1].chars().next().unwrap() as Register - 'a' as Register;
+                let offset = words[2].parse::<Offset>().unwrap();
+                Instruction::JIO(register, offset)
+            }
+            _ => {
+                return Err(InstructionParsingError("invalid instruction".to_string()));
+            }
+        };
+        Ok(instruction)
+    }
+}
+
+pub type Program = Vec<Instruction>;
+
+/// Runs the program until the cursor falls off either end of it, starting from `registers`.
+pub fn run(program: &[Instruction], mut registers: Registers) -> Registers {
+    let mut cursor: Offset = 0;
+    loop {
+        if cursor < 0 || cursor as usize >= program.len() {
+            break;
+        }
+        let instruction = &program[cursor as usize];
+        cursor = match instruction {
+            Instruction::HLF(register) => {
+                registers[*register as usize] /= 2;
+                cursor + 1
+            }
+            Instruction::TPL(register) => {
+                registers[*register as usize] *= 3;
+                cursor + 1
+            }
+            Instruction::INC(register) => {
+                registers[*register as usize] += 1;
+                cursor + 1
+            }
+            Instruction::JMP(offset) => {
+                let index = cursor + offset;
+                if index < 0 {
+                    break;
+                } else {
+                    index
+                }
+            }
+            Instruction::JIE(register, offset) => {
+                let offset = if registers[*register as usize].is_multiple_of(2) {
+                    *offset
+                } else {
+                    1
+                };
+                cursor + offset
+            }
+            Instruction::JIO(register, offset) => {
+                let offset = if registers[*register as usize] == 1 {
+                    *offset
+                } else {
+                    1
+                };
+                cursor + offset
+            }
+        }
+    }
+
+    registers
+}
+
+pub fn parse_program(lines: &[String]) -> Result<Program, InstructionParsingError> {
+    lines
+        .iter()
+        .map(|line| line.parse::<Instruction>())
+        .collect()
+}
+
+pub struct Puzzle;
+
+impl Puzzle {
+    fn register_b(input: &Input, a: u64) -> Answer {
+        let program = parse_program(&input.read_lines()?).map_err(|e| e.0)?;
+        Ok(run(&program, [a, 0])[1].to_string())
+    }
+}
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Self::register_b(input, 0)
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Self::register_b(input, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_program() {
+        let program = vec![
+            Instruction::INC(0),
+            Instruction::JIO(0, 2),
+            Instruction::TPL(0),
+            Instruction::INC(0),
+        ];
+        assert_eq!(run(&program, [0, 0]), [2, 0]);
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_common::{Answer, Input, Solution};
+use itertools::iterate;
+
+const INITIAL_VALUE: usize = 20_151_125;
+const MULTIPLIER: usize = 252_533;
+const MODULUS: usize = 33_554_393;
+
+pub fn code(row: usize, column: usize) -> usize {
+    let max_row = row + column - 1;
+    let steps_to_max_row = max_row * (max_row - 1) / 2; // max_row choose 2
+    let steps = steps_to_max_row + column;
+
+    iterate(INITIAL_VALUE, |curr| (curr * MULTIPLIER) % MODULUS)
+        .take(steps)
+        .last()
+        .unwrap()
+}
+
+/// Same as `code`, but jumps straight to the answer through modular exponentiation.
+pub fn code_2(row: usize, column: usize) -> usize {
+    let max_row = row + column - 1;
+    let steps_to_max_row = max_row * (max_row - 1) / 2; // max_row choose 2
+    let steps = steps_to_max_row + column - 1;
+
+    mod_ab_exp(INITIAL_VALUE, MULTIPLIER, steps, MODULUS)
+}
+
+fn mod_ab_exp(a: usize, b: usize, exp: usize, modulus: usize) -> usize {
+    // uv mod m = (u mod m * v mod m) mod m
+    let a_mod_m = a % modulus;
+    let b_exp_mod_m = mod_exp(b, exp, modulus);
+    (a_mod_m * b_exp_mod_m) % modulus
+}
+
+// Calculates b^e mod m
+fn mod_exp(base: usize, exp: usize, modulus: usize) -> usize {
+    if modulus == 1 {
+        return 0;
+    }
+    let mut c = 1;
+    let (mut b, mut e) = (base % modulus, exp);
+    while e > 0 {
+        if e % 2 == 1 {
+            c = c * b % modulus
+        }
+        e >>= 1;
+        b = b * b % modulus
+    }
+    c
+}
+
+/// Picks the row and column out of the message on the console.
+///
+/// # Example
+/// ```rust
+/// use cop::parse_position;
+///
+/// let message = "Enter the code at row 3010, column 3019.";
+/// assert_eq!(parse_position(message), Some((3010, 3019)));
+/// assert_eq!(parse_position("Enter the code."), None);
+/// ```
+pub fn parse_position(message: &str) -> Option<(usize, usize)> {
+    let mut numbers = message
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>());
+
+    match (numbers.next(), numbers.next()) {
+        (Some(Ok(row)), Some(Ok(column))) => Some((row, column)),
+        _ => None,
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const INPUT: Option<&'static str> = Some(
+        "To continue, please consult the code grid in the manual.  \
+        Enter the code at row 3010, column 3019.",
+    );
+
+    fn part_one(input: &Input) -> Answer {
+        let (row, column) =
+            parse_position(&input.read_to_string()?).ok_or("could not find row and column")?;
+        Ok(code_2(row, column).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code() {
+        assert_eq!(code(1, 1), 20151125);
+        assert_eq!(code(4, 2), 32451966);
+        assert_eq!(code_2(4, 2), 32451966);
+        assert_eq!(code_2(6, 6), 27995004);
+    }
+}
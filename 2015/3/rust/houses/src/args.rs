@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+/// Follows Santa's directions around the grid of houses
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// File containing the directions
+    pub input: PathBuf,
+
+    /// Number of deliverers taking turns, e.g. 2 for Santa and Robo-Santa
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    pub agents: u16,
+
+    /// Print a heatmap of the visited houses
+    #[arg(long)]
+    pub ascii: bool,
+
+    /// Write a heatmap of the visited houses to a PNG file
+    #[arg(long)]
+    pub png: Option<PathBuf>,
+}
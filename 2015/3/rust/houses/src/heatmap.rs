@@ -0,0 +1,103 @@
+//! Renders the houses that got presents, the busier ones brighter, with north at the top.
+
+use std::io::Write;
+
+use crate::{House, Visits};
+
+const RAMP: &[u8] = b" .:-=+*#%@";
+
+/// Smallest and largest coordinates of the visited houses.
+fn bounds(visits: &Visits) -> Option<(House, House)> {
+    visits.keys().fold(None, |bounds, &(x, y)| match bounds {
+        None => Some(((x, y), (x, y))),
+        Some(((x0, y0), (x1, y1))) => Some(((x0.min(x), y0.min(y)), (x1.max(x), y1.max(y)))),
+    })
+}
+
+/// Scales the number of presents to `1..=levels`, leaving 0 for houses that got none.
+fn level(visits: &Visits, house: House, max: u32, levels: u32) -> u32 {
+    match visits.get(&house) {
+        None => 0,
+        Some(&n) if max <= 1 => n.min(1) * levels,
+        Some(&n) => 1 + (n - 1) * (levels - 1) / (max - 1),
+    }
+}
+
+/// Rows of the grid, from north to south.
+fn rows(visits: &Visits, levels: u32) -> Vec<Vec<u32>> {
+    let Some(((x0, y0), (x1, y1))) = bounds(visits) else {
+        return vec![];
+    };
+    let max = visits.values().copied().max().unwrap_or(0);
+    (y0..=y1)
+        .rev()
+        .map(|y| {
+            (x0..=x1)
+                .map(|x| level(visits, (x, y), max, levels))
+                .collect()
+        })
+        .collect()
+}
+
+/// One character per house, from blank for none to `@` for the most presents.
+///
+/// # Example
+/// ```rust
+/// use houses::{deliver, heatmap, parse_directions};
+///
+/// let visits = deliver(&parse_directions("^>v<>").unwrap(), 1);
+/// assert_eq!(heatmap::ascii(&visits), "..\n@@\n");
+/// ```
+pub fn ascii(visits: &Visits) -> String {
+    let levels = RAMP.len() as u32 - 1;
+    rows(visits, levels)
+        .iter()
+        .map(|row| {
+            let mut line: String = row.iter().map(|&l| RAMP[l as usize] as char).collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+/// An 8-bit grayscale PNG with one pixel per house.
+pub fn png(visits: &Visits, writer: impl Write) -> Result<(), png::EncodingError> {
+    let rows = rows(visits, 255);
+    let height = rows.len() as u32;
+    let width = rows.first().map_or(0, |row| row.len()) as u32;
+
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    let data: Vec<u8> = rows.into_iter().flatten().map(|l| l as u8).collect();
+    writer.write_image_data(&data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{deliver, parse_directions};
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(ascii(&Visits::new()), "");
+
+        let visits = deliver(&parse_directions("^^vv^").unwrap(), 1);
+        assert_eq!(ascii(&visits), ".\n@\n+\n");
+    }
+
+    #[test]
+    fn test_png() {
+        let visits = deliver(&parse_directions(">>^").unwrap(), 1);
+        let mut bytes = Vec::new();
+        png(&visits, &mut bytes).unwrap();
+
+        let decoder = png::Decoder::new(bytes.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).unwrap();
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(&buffer[..info.buffer_size()], &[0, 0, 255, 255, 255, 255]);
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use aoc_common::{Answer, Input, Solution};
+
+pub mod heatmap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A character of the path that is not one of `^v<>`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDirectionError {
+    /// 1-based position of the character in the path.
+    pub position: usize,
+    pub found: char,
+}
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid direction {:?} at position {}",
+            self.found, self.position
+        )
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// Parses a path like `^>v<`, ignoring whitespace.
+///
+/// # Example
+/// ```rust
+/// use houses::{parse_directions, Direction};
+///
+/// assert_eq!(parse_directions("^>\n").unwrap(), vec![Direction::Up, Direction::Right]);
+/// assert_eq!(parse_directions("^x").unwrap_err().position, 2);
+/// ```
+pub fn parse_directions(path: &str) -> Result<Vec<Direction>, ParseDirectionError> {
+    path.chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(i, c)| match c {
+            '^' => Ok(Direction::Up),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            found => Err(ParseDirectionError {
+                position: i + 1,
+                found,
+            }),
+        })
+        .collect()
+}
+
+/// Coordinates of a house, with `y` growing northwards.
+pub type House = (i32, i32);
+
+/// Number of presents delivered to every house that got at least one.
+pub type Visits = HashMap<House, u32>;
+
+fn step(curr: House, direction: Direction) -> House {
+    match direction {
+        Direction::Up => (curr.0, curr.1 + 1),
+        Direction::Down => (curr.0, curr.1 - 1),
+        Direction::Left => (curr.0 - 1, curr.1),
+        Direction::Right => (curr.0 + 1, curr.1),
+    }
+}
+
+/// Delivers presents with `agents` deliverers that all start at the origin and take turns
+/// following the directions.
+///
+/// Every agent drops a present at the origin before the first move.
+///
+/// # Example
+/// ```rust
+/// use houses::{deliver, parse_directions};
+///
+/// let directions = parse_directions("^v^v").unwrap();
+/// assert_eq!(deliver(&directions, 1).len(), 2);
+///
+/// let visits = deliver(&directions, 2);
+/// assert_eq!(visits.len(), 5);
+/// assert_eq!(visits[&(0, 0)], 2);
+/// assert_eq!(visits[&(0, -2)], 1);
+/// ```
+pub fn deliver(directions: &[Direction], agents: usize) -> Visits {
+    assert!(
+        agents > 0,
+        "at least one agent is needed to deliver presents"
+    );
+
+    let mut positions = vec![(0, 0); agents];
+    let mut visits = Visits::new();
+    visits.insert((0, 0), agents as u32);
+    for (i, &direction) in directions.iter().enumerate() {
+        let position = &mut positions[i % agents];
+        *position = step(*position, direction);
+        *visits.entry(*position).or_default() += 1;
+    }
+    visits
+}
+
+pub fn count_unique_houses(path: &str) -> Result<usize, ParseDirectionError> {
+    Ok(deliver(&parse_directions(path)?, 1).len())
+}
+
+pub fn count_unique_houses_santa_and_robot(path: &str) -> Result<usize, ParseDirectionError> {
+    Ok(deliver(&parse_directions(path)?, 2).len())
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const INPUT: Option<&'static str> = Some(include_str!("../input"));
+
+    fn part_one(input: &Input) -> Answer {
+        Ok(count_unique_houses(&input.read_to_string()?)?.to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Ok(count_unique_houses_santa_and_robot(&input.read_to_string()?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_unique_houses() {
+        assert_eq!(count_unique_houses(">"), Ok(2));
+        assert_eq!(count_unique_houses("^>v<"), Ok(4));
+        assert_eq!(count_unique_houses("^v^v^v^v^v"), Ok(2));
+    }
+
+    #[test]
+    fn test_count_unique_houses_santa_and_robot() {
+        assert_eq!(count_unique_houses_santa_and_robot("^v"), Ok(3));
+        assert_eq!(count_unique_houses_santa_and_robot("^>v<"), Ok(3));
+        assert_eq!(count_unique_houses_santa_and_robot("^v^v^v^v^v"), Ok(11));
+    }
+
+    #[test]
+    fn test_deliver() {
+        let directions = parse_directions("^>v<").unwrap();
+        let visits = deliver(&directions, 1);
+        assert_eq!(visits[&(0, 0)], 2);
+        assert_eq!(visits.values().sum::<u32>(), 5);
+
+        let visits = deliver(&directions, 4);
+        assert_eq!(visits[&(0, 0)], 4);
+        assert_eq!(visits.len(), 5);
+        assert_eq!(visits.values().sum::<u32>(), 8);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            count_unique_houses("^^ >a"),
+            Err(ParseDirectionError {
+                position: 5,
+                found: 'a'
+            })
+        );
+    }
+}
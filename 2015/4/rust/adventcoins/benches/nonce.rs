@@ -0,0 +1,29 @@
+use adventcoins::{find_nonce, nonce, nonce_par, par_find_nonce, Prefix};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("nonce");
+    group.sample_size(10);
+    for zeros in [3, 4, 5] {
+        let hex = "0".repeat(zeros);
+        let prefix = Prefix::zeros(zeros);
+        group.bench_with_input(BenchmarkId::new("sequential", zeros), &hex, |b, hex| {
+            b.iter(|| nonce(black_box("abcdef"), hex))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", zeros), &hex, |b, hex| {
+            b.iter(|| nonce_par(black_box("abcdef"), hex))
+        });
+        group.bench_with_input(BenchmarkId::new("fast", zeros), &prefix, |b, prefix| {
+            b.iter(|| find_nonce(black_box("abcdef"), prefix))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("fast-parallel", zeros),
+            &prefix,
+            |b, prefix| b.iter(|| par_find_nonce(black_box("abcdef"), prefix)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, strategies);
+criterion_main!(benches);
@@ -1,28 +1,64 @@
-use clap::{builder::PossibleValue,Parser,ValueEnum};
+use clap::{builder::PossibleValue, Args as Group, Parser, ValueEnum};
 
 #[derive(Debug, Clone)]
-pub enum Strategy {
-    Sequential,
-    Parallel
+pub enum HashFunction {
+    Md5,
+    Sha256,
 }
 
-impl ValueEnum for Strategy {
+impl ValueEnum for HashFunction {
     fn value_variants<'a>() -> &'a [Self] {
-        &[Self::Sequential, Self::Parallel]
+        &[Self::Md5, Self::Sha256]
     }
 
     fn to_possible_value(&self) -> Option<PossibleValue> {
         Some(match self {
-            Self::Sequential => PossibleValue::new("sequential").help("use sequential strategy"),
-            Self::Parallel => PossibleValue::new("parallel").help("use parallel strategy"),
+            Self::Md5 => PossibleValue::new("md5"),
+            Self::Sha256 => PossibleValue::new("sha256"),
         })
     }
 }
 
-#[derive(Debug,Parser)]
+/// What the digest of a nonce must look like. Defaults to 5 leading zeros.
+#[derive(Debug, Group)]
+#[group(multiple = false)]
+pub struct Condition {
+    /// Number of leading zeros in the hex digest
+    pub leading_zeros: Option<usize>,
+
+    /// Number of leading zero bits in the digest
+    #[arg(long)]
+    pub zero_bits: Option<u32>,
+
+    /// Hex prefix of the digest
+    #[arg(long)]
+    pub prefix: Option<String>,
+
+    /// Hex number the leading bytes of the digest must be below
+    #[arg(long)]
+    pub target: Option<String>,
+}
+
+#[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
     pub message: String,
-    pub leading_zeros: usize,
-    pub strategy: Option<Strategy>,
-}
\ No newline at end of file
+
+    #[command(flatten)]
+    pub condition: Condition,
+
+    #[arg(long, value_enum, default_value = "md5")]
+    pub hash: HashFunction,
+
+    /// Nonce to start from, e.g. to resume an earlier search
+    #[arg(long, default_value_t = 1)]
+    pub start: u64,
+
+    /// Number of threads to search with, all cores by default
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// Report how far the search got every so often
+    #[arg(long)]
+    pub progress: bool,
+}
@@ -0,0 +1,48 @@
+use sha2::Digest;
+
+/// A hash function that candidates are run through.
+pub trait Digester: Sync {
+    type Digest: AsRef<[u8]>;
+
+    fn digest(&self, message: &[u8]) -> Self::Digest;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Md5;
+
+impl Digester for Md5 {
+    type Digest = [u8; 16];
+
+    fn digest(&self, message: &[u8]) -> [u8; 16] {
+        md5::compute(message).0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256;
+
+impl Digester for Sha256 {
+    type Digest = [u8; 32];
+
+    fn digest(&self, message: &[u8]) -> [u8; 32] {
+        sha2::Sha256::digest(message).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_digests() {
+        assert_eq!(hex(&Md5.digest(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex(&Sha256.digest(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
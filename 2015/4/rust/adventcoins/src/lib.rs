@@ -1,22 +1,44 @@
-use md5;
+use aoc_common::{Answer, Input, Solution};
 use rayon::prelude::*;
 
+mod hash;
+mod predicate;
+mod search;
+pub use hash::{Digester, Md5, Sha256};
+pub use predicate::{LeadingZeroBits, ParsePrefixError, Predicate, Prefix, Target};
+pub use search::{find_nonce, par_find_nonce, Search};
+
 pub fn nonce(msg: &str, prefix: &str) -> Option<u64> {
-    (1u64..u64::MAX)
-        .into_iter()
-        .find(|&i| {
-            let word = std::format!("{}{}", msg, i);
-            let digest = md5::compute(word);
-            std::format!("{:x}", digest).starts_with(prefix)
-        })
+    (1u64..u64::MAX).find(|&i| {
+        let word = std::format!("{}{}", msg, i);
+        let digest = md5::compute(word);
+        std::format!("{:x}", digest).starts_with(prefix)
+    })
 }
 
 pub fn nonce_par(msg: &str, prefix: &str) -> Option<u64> {
-    (1u64..u64::MAX)
-        .into_par_iter()
-        .find_first(|&i| {
-            let word = std::format!("{}{}", msg, i);
-            let digest = md5::compute(word);
-            std::format!("{:x}", digest).starts_with(prefix)
-        })
+    (1u64..u64::MAX).into_par_iter().find_first(|&i| {
+        let word = std::format!("{}{}", msg, i);
+        let digest = md5::compute(word);
+        std::format!("{:x}", digest).starts_with(prefix)
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        mine(input, 5)
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        mine(input, 6)
+    }
+}
+
+fn mine(input: &Input, leading_zeros: usize) -> Answer {
+    let key = input.read_to_string()?;
+    let value =
+        par_find_nonce(key.trim(), &Prefix::zeros(leading_zeros)).ok_or("no nonce found")?;
+    Ok(value.to_string())
 }
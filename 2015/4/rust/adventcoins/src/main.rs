@@ -1,23 +1,58 @@
 mod args;
 
+use std::error::Error;
 use std::time::Instant;
 
-use adventcoins::{nonce, nonce_par};
-use args::{Args, Strategy};
+use adventcoins::{Digester, LeadingZeroBits, Md5, Predicate, Prefix, Search, Sha256, Target};
+use args::{Args, Condition, HashFunction};
 use clap::Parser;
 
-fn main() {
+fn predicate(condition: &Condition) -> Result<Box<dyn Predicate>, Box<dyn Error>> {
+    Ok(match condition {
+        Condition {
+            zero_bits: Some(bits),
+            ..
+        } => Box::new(LeadingZeroBits(*bits)),
+        Condition {
+            prefix: Some(prefix),
+            ..
+        } => Box::new(prefix.parse::<Prefix>()?),
+        Condition {
+            target: Some(target),
+            ..
+        } => Box::new(target.parse::<Target>()?),
+        Condition { leading_zeros, .. } => Box::new(Prefix::zeros(leading_zeros.unwrap_or(5))),
+    })
+}
+
+fn search<H: Digester>(args: &Args, hash: H, predicate: Box<dyn Predicate>) -> Option<u64> {
+    let search = Search::new(hash, predicate).start(args.start);
+    let progress = |nonce| {
+        if args.progress {
+            eprintln!("searched up to {}", nonce);
+        }
+    };
+    search.par_find_with_progress(&args.message, progress)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
     println!("args = {:?}", args);
-    let zeros = "0".repeat(args.leading_zeros);
-    let solver = match args.strategy {
-        Some(Strategy::Parallel) => nonce_par,
-        _ => nonce
-    };
+    let predicate = predicate(&args.condition)?;
+    let mut pool = rayon::ThreadPoolBuilder::new();
+    if let Some(threads) = args.threads {
+        pool = pool.num_threads(threads);
+    }
+    let pool = pool.build()?;
+
     let start = Instant::now();
-    let value = solver(&args.message, &zeros);
+    let value = pool.install(|| match args.hash {
+        HashFunction::Md5 => search(&args, Md5, predicate),
+        HashFunction::Sha256 => search(&args, Sha256, predicate),
+    });
     let duration = start.elapsed();
 
     println!("value = {:?}", value);
     println!("Elapsed time = {:?}", duration);
+    Ok(())
 }
@@ -0,0 +1,190 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// What a digest must look like for its nonce to be accepted.
+pub trait Predicate: Send + Sync {
+    fn matches(&self, digest: &[u8]) -> bool;
+}
+
+impl<P: Predicate + ?Sized> Predicate for &P {
+    fn matches(&self, digest: &[u8]) -> bool {
+        (**self).matches(digest)
+    }
+}
+
+impl<P: Predicate + ?Sized> Predicate for Box<P> {
+    fn matches(&self, digest: &[u8]) -> bool {
+        (**self).matches(digest)
+    }
+}
+
+/// A hex prefix, matched against the raw bytes of a digest instead of its hex representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prefix {
+    bytes: Vec<u8>,
+    /// High nibble of the byte after `bytes`, for prefixes of odd length.
+    nibble: Option<u8>,
+}
+
+impl Prefix {
+    /// A prefix of `n` zeros, like the ones AdventCoins are mined with.
+    pub fn zeros(n: usize) -> Prefix {
+        Prefix {
+            bytes: vec![0; n / 2],
+            nibble: (n % 2 == 1).then_some(0),
+        }
+    }
+
+    /// Whether the hex representation of `digest` starts with this prefix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use adventcoins::Prefix;
+    ///
+    /// let prefix: Prefix = "00f".parse().unwrap();
+    /// assert!(prefix.matches(&[0x00, 0xf3, 0x12]));
+    /// assert!(!prefix.matches(&[0x00, 0x3f, 0x12]));
+    /// assert!(!prefix.matches(&[0x00]));
+    /// ```
+    pub fn matches(&self, digest: &[u8]) -> bool {
+        let n = self.bytes.len();
+        digest.starts_with(&self.bytes)
+            && match self.nibble {
+                None => true,
+                Some(nibble) => digest.get(n).is_some_and(|b| b >> 4 == nibble),
+            }
+    }
+}
+
+impl Predicate for Prefix {
+    fn matches(&self, digest: &[u8]) -> bool {
+        Prefix::matches(self, digest)
+    }
+}
+
+/// A string that has something other than hex digits in it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePrefixError(pub char);
+
+impl fmt::Display for ParsePrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid hex digit {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePrefixError {}
+
+fn nibbles(s: &str) -> Result<Vec<u8>, ParsePrefixError> {
+    s.chars()
+        .map(|c| c.to_digit(16).map(|d| d as u8).ok_or(ParsePrefixError(c)))
+        .collect()
+}
+
+impl FromStr for Prefix {
+    type Err = ParsePrefixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nibbles = nibbles(s)?;
+        Ok(Prefix {
+            bytes: nibbles.chunks_exact(2).map(|p| p[0] << 4 | p[1]).collect(),
+            nibble: (nibbles.len() % 2 == 1).then(|| nibbles[nibbles.len() - 1]),
+        })
+    }
+}
+
+/// Digests that start with at least this many zero bits.
+///
+/// # Example
+/// ```rust
+/// use adventcoins::{LeadingZeroBits, Predicate};
+///
+/// assert!(LeadingZeroBits(11).matches(&[0x00, 0x1f]));
+/// assert!(!LeadingZeroBits(12).matches(&[0x00, 0x1f]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeadingZeroBits(pub u32);
+
+impl Predicate for LeadingZeroBits {
+    fn matches(&self, digest: &[u8]) -> bool {
+        let mut zeros = 0;
+        for &byte in digest {
+            zeros += byte.leading_zeros();
+            if zeros >= self.0 || byte != 0 {
+                break;
+            }
+        }
+        zeros >= self.0
+    }
+}
+
+/// Digests whose leading bytes, read as a big-endian number, are below the target.
+///
+/// # Example
+/// ```rust
+/// use adventcoins::{Predicate, Target};
+///
+/// let target: Target = "0040".parse().unwrap();
+/// assert!(target.matches(&[0x00, 0x3f, 0xff]));
+/// assert!(!target.matches(&[0x00, 0x40, 0x00]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target(Vec<u8>);
+
+impl Predicate for Target {
+    fn matches(&self, digest: &[u8]) -> bool {
+        digest.len() >= self.0.len() && digest[..self.0.len()] < self.0[..]
+    }
+}
+
+impl FromStr for Target {
+    type Err = ParsePrefixError;
+
+    /// Parses a target from hex, padding odd lengths with a trailing zero.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut nibbles = nibbles(s)?;
+        if nibbles.len() % 2 == 1 {
+            nibbles.push(0);
+        }
+        Ok(Target(
+            nibbles.chunks_exact(2).map(|p| p[0] << 4 | p[1]).collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix() {
+        assert_eq!("".parse::<Prefix>().unwrap(), Prefix::zeros(0));
+        assert_eq!("00000".parse::<Prefix>().unwrap(), Prefix::zeros(5));
+        assert_eq!("000000".parse::<Prefix>().unwrap(), Prefix::zeros(6));
+        assert_eq!("0g".parse::<Prefix>(), Err(ParsePrefixError('g')));
+
+        let digest = md5::compute("abcdef609043").0;
+        let hex = format!("{:x}", md5::Digest(digest));
+        for len in 0..=hex.len() {
+            assert!(hex[..len].parse::<Prefix>().unwrap().matches(&digest));
+        }
+        assert!(!Prefix::zeros(6).matches(&digest));
+    }
+
+    #[test]
+    fn test_leading_zero_bits() {
+        assert!(LeadingZeroBits(0).matches(&[0xff]));
+        assert!(LeadingZeroBits(16).matches(&[0, 0]));
+        assert!(!LeadingZeroBits(17).matches(&[0, 0]));
+        assert!(LeadingZeroBits(20).matches(&[0, 0, 0x0f]));
+        assert!(!LeadingZeroBits(20).matches(&[0, 0x01, 0]));
+    }
+
+    #[test]
+    fn test_target() {
+        let target: Target = "001".parse().unwrap();
+        assert_eq!(target, Target(vec![0x00, 0x10]));
+        assert!(target.matches(&[0x00, 0x0f, 0xff]));
+        assert!(!target.matches(&[0x00, 0x10, 0x00]));
+        assert!(!target.matches(&[0x00]));
+    }
+}
@@ -0,0 +1,213 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rayon::prelude::*;
+
+use crate::{Digester, Md5, Predicate, Prefix};
+
+/// The secret key followed by a nonce, rewritten in place for every candidate.
+struct Message {
+    buffer: Vec<u8>,
+    key_len: usize,
+}
+
+impl Message {
+    fn new(key: &str) -> Message {
+        let mut buffer = Vec::with_capacity(key.len() + 20);
+        buffer.extend_from_slice(key.as_bytes());
+        Message {
+            buffer,
+            key_len: key.len(),
+        }
+    }
+
+    fn with_nonce(&mut self, mut nonce: u64) -> &[u8] {
+        let mut digits = [0u8; 20];
+        let mut i = digits.len();
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (nonce % 10) as u8;
+            nonce /= 10;
+            if nonce == 0 {
+                break;
+            }
+        }
+        self.buffer.truncate(self.key_len);
+        self.buffer.extend_from_slice(&digits[i..]);
+        &self.buffer
+    }
+}
+
+const CHUNK_SIZE: u64 = 4096;
+
+/// A proof-of-work search for the lowest nonce that, appended to a key, hashes to a digest
+/// accepted by the predicate.
+///
+/// # Example
+/// ```rust
+/// use adventcoins::{LeadingZeroBits, Search, Sha256};
+///
+/// let search = Search::new(Sha256, LeadingZeroBits(12));
+/// let nonce = search.find("abcdef").unwrap();
+/// assert_eq!(search.start(nonce + 1).par_find("abcdef").map(|n| n > nonce), Some(true));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Search<H, P> {
+    hash: H,
+    predicate: P,
+    start: u64,
+    chunk_size: u64,
+}
+
+impl<H: Digester, P: Predicate> Search<H, P> {
+    pub fn new(hash: H, predicate: P) -> Self {
+        Search {
+            hash,
+            predicate,
+            start: 1,
+            chunk_size: CHUNK_SIZE,
+        }
+    }
+
+    /// Resumes a search from `nonce` instead of 1.
+    pub fn start(self, nonce: u64) -> Self {
+        Search {
+            start: nonce,
+            ..self
+        }
+    }
+
+    fn scan(&self, message: &mut Message, mut nonces: impl Iterator<Item = u64>) -> Option<u64> {
+        nonces.find(|&nonce| {
+            let digest = self.hash.digest(message.with_nonce(nonce));
+            self.predicate.matches(digest.as_ref())
+        })
+    }
+
+    pub fn find(&self, key: &str) -> Option<u64> {
+        self.find_with_progress(key, |_| ())
+    }
+
+    /// Same as [`Search::find`], calling `progress` with the next nonce to try every so often.
+    /// All the nonces before it have been ruled out, so the search can be resumed from there.
+    pub fn find_with_progress(&self, key: &str, progress: impl Fn(u64)) -> Option<u64> {
+        let mut message = Message::new(key);
+        let mut from = self.start;
+        while from < u64::MAX {
+            let to = from.saturating_add(self.chunk_size);
+            if let Some(nonce) = self.scan(&mut message, from..to) {
+                return Some(nonce);
+            }
+            from = to;
+            progress(from);
+        }
+        None
+    }
+
+    /// Parallel version of [`Search::find`] that still returns the lowest nonce. It runs on the
+    /// current rayon thread pool.
+    pub fn par_find(&self, key: &str) -> Option<u64> {
+        self.par_find_with_progress(key, |_| ())
+    }
+
+    /// Parallel version of [`Search::find_with_progress`].
+    ///
+    /// Nonces are handed out in rounds of one chunk per thread. Once a nonce is found, chunks
+    /// that start past it are skipped and the search stops at the end of the round.
+    pub fn par_find_with_progress(&self, key: &str, progress: impl Fn(u64)) -> Option<u64> {
+        let chunks = rayon::current_num_threads() as u64;
+        let best = AtomicU64::new(u64::MAX);
+        let mut start = self.start;
+        while start < u64::MAX {
+            (0..chunks).into_par_iter().for_each_init(
+                || Message::new(key),
+                |message, chunk| {
+                    let from = start.saturating_add(chunk * self.chunk_size);
+                    let to = from.saturating_add(self.chunk_size);
+                    let nonces = (from..to).take_while(|&n| n < best.load(Ordering::Relaxed));
+                    if let Some(nonce) = self.scan(message, nonces) {
+                        best.fetch_min(nonce, Ordering::Relaxed);
+                    }
+                },
+            );
+            match best.load(Ordering::Relaxed) {
+                u64::MAX => start = start.saturating_add(chunks * self.chunk_size),
+                nonce => return Some(nonce),
+            }
+            progress(start);
+        }
+        None
+    }
+}
+
+/// Same as [`crate::nonce`], without formatting digests or allocating per candidate.
+///
+/// # Example
+/// ```rust
+/// use adventcoins::{find_nonce, Prefix};
+///
+/// assert_eq!(find_nonce("abcdef", &Prefix::zeros(5)), Some(609043));
+/// ```
+pub fn find_nonce(key: &str, prefix: &Prefix) -> Option<u64> {
+    Search::new(Md5, prefix).find(key)
+}
+
+/// Parallel version of [`find_nonce`] that still returns the lowest nonce.
+pub fn par_find_nonce(key: &str, prefix: &Prefix) -> Option<u64> {
+    Search::new(Md5, prefix).par_find(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LeadingZeroBits, Sha256};
+    use std::cell::Cell;
+
+    #[test]
+    fn test_message() {
+        let mut message = Message::new("abc");
+        assert_eq!(message.with_nonce(0), b"abc0");
+        assert_eq!(message.with_nonce(1234567), b"abc1234567");
+        assert_eq!(message.with_nonce(42), b"abc42");
+        assert_eq!(message.with_nonce(u64::MAX), b"abc18446744073709551615");
+    }
+
+    #[test]
+    fn test_par_find() {
+        let prefix = Prefix::zeros(3);
+        for key in ["abcdef", "pqrstuv", "yzbqklnj"] {
+            let expected = crate::nonce(key, "000");
+            assert_eq!(find_nonce(key, &prefix), expected);
+            for chunk_size in [1, 7, 100, 10_000] {
+                let search = Search {
+                    chunk_size,
+                    ..Search::new(Md5, &prefix)
+                };
+                assert_eq!(search.par_find(key), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_resume() {
+        let search = Search::new(Sha256, LeadingZeroBits(8));
+        let first = search.find("abc").unwrap();
+        let second = search.clone().start(first + 1).find("abc").unwrap();
+        assert!(second > first);
+        assert_eq!(search.start(first).par_find("abc"), Some(first));
+    }
+
+    #[test]
+    fn test_progress() {
+        let search = Search {
+            chunk_size: 10,
+            ..Search::new(Md5, Prefix::zeros(3))
+        };
+        let last = Cell::new(0);
+        let nonce = search.find_with_progress("abcdef", |n| {
+            assert!(n > last.get());
+            last.set(n);
+        });
+        assert!(last.get() <= nonce.unwrap());
+        assert_eq!(last.get() % 10, 1);
+    }
+}
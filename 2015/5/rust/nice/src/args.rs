@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+/// Tells nice strings from naughty ones
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// Puzzle part whose rules to use
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    /// File with one rule per line, instead of the rules of a puzzle part
+    #[arg(long, conflicts_with = "part")]
+    pub rules: Option<PathBuf>,
+
+    /// List the rules broken by every naughty string
+    #[arg(long)]
+    pub explain: bool,
+
+    /// File containing the strings, stdin if missing or `-`
+    pub input: Option<String>,
+}
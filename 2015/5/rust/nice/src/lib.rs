@@ -0,0 +1,63 @@
+use aoc_common::{Answer, Input, Solution};
+
+mod rules;
+pub use rules::{ParseRuleError, Rule, RuleSet};
+
+pub fn is_nice_string(text: &str) -> bool {
+    RuleSet::part_one().is_nice(text)
+}
+
+pub fn is_nice_string_2(text: &str) -> bool {
+    RuleSet::part_two().is_nice(text)
+}
+
+pub struct Puzzle;
+
+impl Puzzle {
+    fn count_nice(input: &Input, rules: RuleSet) -> Answer {
+        let lines = input.read_lines()?;
+        Ok(lines
+            .iter()
+            .filter(|y| rules.is_nice(y))
+            .count()
+            .to_string())
+    }
+}
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Puzzle::count_nice(input, RuleSet::part_one())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Puzzle::count_nice(input, RuleSet::part_two())
+    }
+}
+
+#[test]
+fn test_nice_strings() {
+    for nice in ["ugknbfddgicrmopn", "aaa"] {
+        assert!(is_nice_string(nice));
+    }
+}
+
+#[test]
+fn test_naughty_strings() {
+    for naughty in ["jchzalrnumimnmhp", "haegwjzuvuyypxyu", "dvszwmarrgswjxmb"] {
+        assert!(!is_nice_string(naughty));
+    }
+}
+
+#[test]
+fn test_nice_strings2() {
+    for nice in ["qjhvhtzxzqqjkmpb", "xxyxx"] {
+        assert!(is_nice_string_2(nice));
+    }
+}
+
+#[test]
+fn test_naughty_strings_2() {
+    for naughty in ["uurcxstgmygtbstg", "ieodomkazucvgmuy"] {
+        assert!(!is_nice_string_2(naughty));
+    }
+}
@@ -1,106 +1,32 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{self, BufRead};
-
-fn lines() -> Box<dyn Iterator<Item = String>> {
-    match std::env::args().nth(2) {
-        None => Box::new(io::stdin().lock().lines().filter_map(Result::ok)),
-        Some(path) => {
-            let file = File::open(path).expect("error reading file");
-            Box::new(io::BufReader::new(file).lines().filter_map(Result::ok))
-        }
-    }
-}
-
-fn main() {
-    let fun = match std::env::args().nth(1).map(|s| s.parse::<usize>()) {
-        Some(Ok(1)) => is_nice_string,
-        Some(Ok(2)) => is_nice_string_2,
-        _ => panic!("invalid option. choose 1 or 2"),
+mod args;
+
+use std::error::Error;
+use std::fs;
+
+use aoc_common::{Input, Source};
+use args::Args;
+use clap::Parser;
+use nice::RuleSet;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let rules = match (&args.rules, args.part) {
+        (Some(path), _) => fs::read_to_string(path)?.parse()?,
+        (None, 1) => RuleSet::part_one(),
+        (None, _) => RuleSet::part_two(),
     };
-    println!("{}", lines().filter(|y| fun(y)).collect::<Vec<_>>().len());
-}
-
-fn is_vowel(c: &char) -> bool {
-    match c {
-        'a' | 'e' | 'i' | 'o' | 'u' => true,
-        _ => false,
-    }
-}
-
-fn has_letter_that_appears_twice_in_a_row(text: &str) -> bool {
-    text.chars()
-        .zip(text.chars().skip(1))
-        .any(|(curr, char)| curr == char)
-}
-
-const BLACKLIST: [&str; 4] = ["ab", "cd", "pq", "xy"];
-
-fn does_not_contain_blacklisted_substrings(text: &str) -> bool {
-    !BLACKLIST.into_iter().any(|s| text.contains(s))
-}
-
-fn is_nice_string(text: &str) -> bool {
-    let vowel_count = text.chars().filter(is_vowel).collect::<Vec<_>>().len();
-
-    vowel_count >= 3
-        && has_letter_that_appears_twice_in_a_row(text)
-        && does_not_contain_blacklisted_substrings(text)
-}
-
-fn is_nice_string_2(text: &str) -> bool {
-    has_two_pairs_with_no_overlapping(text) && has_letter_sandwich(text)
-}
-
-fn has_two_pairs_with_no_overlapping(txt: &str) -> bool {
-    let pairs = txt.chars().zip(txt.chars().skip(1));
-    let pair_map: HashMap<(char, char), Vec<usize>> = HashMap::new();
-    let indices_map = pairs.enumerate().fold(pair_map, |mut acc, curr| {
-        let (index, pair) = curr;
-        let s = acc.entry(pair).or_insert(Vec::new());
-        s.push(index);
-        acc
-    });
-
-    indices_map.iter().any(|(_, indices)| {
-        indices.len() > 2
-            || indices
-                .iter()
-                .zip(indices.iter().skip(1))
-                .any(|(curr, succ)| curr.abs_diff(*succ) > 1)
-    })
-}
-
-fn has_letter_sandwich(text: &str) -> bool {
-    text.chars()
-        .zip(text.chars().skip(1).zip(text.chars().skip(2)))
-        .any(|(left, (_, right))| left == right)
-}
-
-#[test]
-fn test_nice_strings() {
-    for nice in vec!["ugknbfddgicrmopn", "aaa"] {
-        assert_eq!(is_nice_string(nice), true);
-    }
-}
-
-#[test]
-fn test_naughty_strings() {
-    for naughty in vec!["jchzalrnumimnmhp", "haegwjzuvuyypxyu", "dvszwmarrgswjxmb"] {
-        assert_eq!(is_nice_string(naughty), false);
-    }
-}
-
-#[test]
-fn test_nice_strings2() {
-    for nice in vec!["qjhvhtzxzqqjkmpb", "xxyxx"] {
-        assert!(is_nice_string_2(nice));
-    }
-}
-
-#[test]
-fn test_naughty_strings_2() {
-    for naughty in vec!["uurcxstgmygtbstg", "ieodomkazucvgmuy"] {
-        assert_eq!(is_nice_string_2(naughty), false);
+    let lines = Input::new(Source::from_arg(args.input)).read_lines()?;
+
+    let mut nice = 0;
+    for line in &lines {
+        let failures = rules.failures(line);
+        if failures.is_empty() {
+            nice += 1;
+        } else if args.explain {
+            let failures: Vec<String> = failures.iter().map(|rule| rule.to_string()).collect();
+            println!("{}: {}", line, failures.join(", "));
+        }
     }
+    println!("{}", nice);
+    Ok(())
 }
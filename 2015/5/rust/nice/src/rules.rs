@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A single check a nice string has to pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// At least this many vowels, `aeiou` only.
+    MinVowels(usize),
+    /// A letter that appears twice in a row, like `xx`.
+    DoubleLetter,
+    /// None of these substrings.
+    ForbiddenSubstrings(Vec<String>),
+    /// A pair of letters that appears twice without overlapping, like `xyxy` but not `aaa`.
+    RepeatingPairNonOverlapping,
+    /// A letter that repeats with exactly one letter in between, like `xyx`.
+    Sandwich,
+}
+
+fn is_vowel(c: &char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+fn has_two_pairs_with_no_overlapping(txt: &str) -> bool {
+    let pairs = txt.chars().zip(txt.chars().skip(1));
+    let pair_map: HashMap<(char, char), Vec<usize>> = HashMap::new();
+    let indices_map = pairs.enumerate().fold(pair_map, |mut acc, curr| {
+        let (index, pair) = curr;
+        let s = acc.entry(pair).or_default();
+        s.push(index);
+        acc
+    });
+
+    indices_map.iter().any(|(_, indices)| {
+        indices.len() > 2
+            || indices
+                .iter()
+                .zip(indices.iter().skip(1))
+                .any(|(curr, succ)| curr.abs_diff(*succ) > 1)
+    })
+}
+
+impl Rule {
+    pub fn check(&self, text: &str) -> bool {
+        match self {
+            Rule::MinVowels(n) => text.chars().filter(is_vowel).count() >= *n,
+            Rule::DoubleLetter => text
+                .chars()
+                .zip(text.chars().skip(1))
+                .any(|(curr, next)| curr == next),
+            Rule::ForbiddenSubstrings(forbidden) => !forbidden.iter().any(|s| text.contains(s)),
+            Rule::RepeatingPairNonOverlapping => has_two_pairs_with_no_overlapping(text),
+            Rule::Sandwich => text
+                .chars()
+                .zip(text.chars().skip(2))
+                .any(|(left, right)| left == right),
+        }
+    }
+}
+
+/// Rules are written the way they are parsed, e.g. `forbidden-substrings ab cd`.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::MinVowels(n) => write!(f, "min-vowels {}", n),
+            Rule::DoubleLetter => write!(f, "double-letter"),
+            Rule::ForbiddenSubstrings(forbidden) => {
+                write!(f, "forbidden-substrings {}", forbidden.join(" "))
+            }
+            Rule::RepeatingPairNonOverlapping => write!(f, "repeating-pair-non-overlapping"),
+            Rule::Sandwich => write!(f, "sandwich"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRuleError(pub String);
+
+impl fmt::Display for ParseRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ParseRuleError {}
+
+impl FromStr for Rule {
+    type Err = ParseRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let args: Vec<&str> = words.collect();
+        let no_args = |rule| match args[..] {
+            [] => Ok(rule),
+            _ => Err(ParseRuleError(format!("{} takes no arguments", name))),
+        };
+        match name {
+            "min-vowels" => match args[..] {
+                [n] => n
+                    .parse()
+                    .map(Rule::MinVowels)
+                    .map_err(|_| ParseRuleError(format!("invalid number of vowels {:?}", n))),
+                _ => Err(ParseRuleError("min-vowels takes a number".to_string())),
+            },
+            "double-letter" => no_args(Rule::DoubleLetter),
+            "forbidden-substrings" => Ok(Rule::ForbiddenSubstrings(
+                args.iter().map(|s| s.to_string()).collect(),
+            )),
+            "repeating-pair-non-overlapping" => no_args(Rule::RepeatingPairNonOverlapping),
+            "sandwich" => no_args(Rule::Sandwich),
+            _ => Err(ParseRuleError(format!("unknown rule {:?}", name))),
+        }
+    }
+}
+
+/// Rules that must all pass for a string to be nice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet(pub Vec<Rule>);
+
+impl RuleSet {
+    /// The rules of the first part of the puzzle.
+    pub fn part_one() -> RuleSet {
+        RuleSet(vec![
+            Rule::MinVowels(3),
+            Rule::DoubleLetter,
+            Rule::ForbiddenSubstrings(["ab", "cd", "pq", "xy"].map(String::from).to_vec()),
+        ])
+    }
+
+    /// The rules of the second part of the puzzle.
+    pub fn part_two() -> RuleSet {
+        RuleSet(vec![Rule::RepeatingPairNonOverlapping, Rule::Sandwich])
+    }
+
+    pub fn is_nice(&self, text: &str) -> bool {
+        self.0.iter().all(|rule| rule.check(text))
+    }
+
+    /// The rules that `text` breaks, empty for nice strings.
+    ///
+    /// # Example
+    /// ```rust
+    /// use nice::{Rule, RuleSet};
+    ///
+    /// let rules = RuleSet::part_one();
+    /// assert_eq!(rules.failures("ugknbfddgicrmopn"), Vec::<&Rule>::new());
+    /// assert_eq!(rules.failures("jchzalrnumimnmhp"), vec![&Rule::DoubleLetter]);
+    /// ```
+    pub fn failures(&self, text: &str) -> Vec<&Rule> {
+        self.0.iter().filter(|rule| !rule.check(text)).collect()
+    }
+}
+
+/// One rule per line. Blank lines and lines starting with `#` are ignored.
+///
+/// # Example
+/// ```rust
+/// use nice::RuleSet;
+///
+/// let rules: RuleSet = "# part two\nrepeating-pair-non-overlapping\nsandwich\n".parse().unwrap();
+/// assert_eq!(rules, RuleSet::part_two());
+/// ```
+impl FromStr for RuleSet {
+    type Err = ParseRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(i, line)| {
+                line.parse()
+                    .map_err(|e: ParseRuleError| ParseRuleError(format!("line {}: {}", i + 1, e)))
+            })
+            .collect::<Result<_, _>>()
+            .map(RuleSet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule() {
+        assert_eq!("min-vowels 3".parse(), Ok(Rule::MinVowels(3)));
+        assert_eq!(
+            "forbidden-substrings ab  cd".parse(),
+            Ok(Rule::ForbiddenSubstrings(vec!["ab".into(), "cd".into()]))
+        );
+        assert!("min-vowels".parse::<Rule>().is_err());
+        assert!("min-vowels x".parse::<Rule>().is_err());
+        assert!("sandwich 2".parse::<Rule>().is_err());
+        assert!("".parse::<Rule>().is_err());
+
+        for rule in RuleSet::part_one()
+            .0
+            .into_iter()
+            .chain(RuleSet::part_two().0)
+        {
+            assert_eq!(rule.to_string().parse(), Ok(rule));
+        }
+    }
+
+    #[test]
+    fn test_parse_rule_set() {
+        assert_eq!(
+            include_str!("../rules/part1.rules").parse(),
+            Ok(RuleSet::part_one())
+        );
+        assert_eq!(
+            include_str!("../rules/part2.rules").parse(),
+            Ok(RuleSet::part_two())
+        );
+        assert_eq!(
+            "sandwich\n\nnope\n".parse::<RuleSet>(),
+            Err(ParseRuleError("line 3: unknown rule \"nope\"".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        let rules = RuleSet::part_one();
+        assert_eq!(
+            rules.failures("haegwjzuvuyypxyu"),
+            vec![&Rule::ForbiddenSubstrings(
+                ["ab", "cd", "pq", "xy"].map(String::from).to_vec()
+            )]
+        );
+        assert_eq!(
+            rules.failures("dvszwmarrgswjxmb"),
+            vec![&Rule::MinVowels(3)]
+        );
+        assert_eq!(
+            RuleSet::part_two().failures("ieodomkazucvgmuy"),
+            vec![&Rule::RepeatingPairNonOverlapping]
+        );
+    }
+}
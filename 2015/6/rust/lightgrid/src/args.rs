@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use lightgrid::{Backend, Semantics};
+
+/// Follows Santa's instructions on the light grid
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// File containing the instructions, stdin if missing or `-`
+    pub input: Option<String>,
+
+    /// How to store the grid
+    #[arg(long, value_enum, default_value_t = Backend::default())]
+    pub backend: Backend,
+
+    /// What the instructions do to the lights
+    #[arg(long, value_enum, default_value_t = Semantics::default())]
+    pub semantics: Semantics,
+
+    /// Lights never get brighter than this
+    #[arg(long)]
+    pub max_brightness: Option<u32>,
+
+    /// Write the final grid to a PGM file
+    #[arg(long)]
+    pub pgm: Option<PathBuf>,
+
+    /// Write the final grid to a PNG file
+    #[arg(long)]
+    pub png: Option<PathBuf>,
+
+    /// Write an animation of the grid, one frame per instruction, to a GIF file
+    #[arg(long)]
+    pub gif: Option<PathBuf>,
+
+    /// How long each frame of the animation is shown, in hundredths of a second
+    #[arg(long, default_value_t = 4)]
+    pub delay: u16,
+}
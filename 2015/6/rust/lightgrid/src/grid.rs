@@ -0,0 +1,420 @@
+use std::collections::HashMap;
+
+use clap::{builder::PossibleValue, ValueEnum};
+use rayon::prelude::*;
+
+use crate::models::{GridPoint, Op, Rect};
+use crate::semantics::{Brightness, LightSemantics};
+
+/// Storage for the brightness of every light, updated one operation at a time.
+pub trait Grid {
+    fn apply(&mut self, op: &Op);
+
+    fn brightness(&self, point: GridPoint) -> u32;
+
+    fn total_brightness(&self) -> usize;
+}
+
+/// Most lights [`Backend::Dense`] and [`Backend::Parallel`] allocate before falling back to a
+/// [`CompressedGrid`].
+pub const MAX_DENSE_LIGHTS: usize = 1 << 24;
+
+/// Smallest width and height that fit every operation.
+pub fn bounds(ops: &[Op]) -> (usize, usize) {
+    ops.iter().fold((0, 0), |(width, height), op| {
+        let corner = op.rect().top_right_corner;
+        (
+            width.max(corner.x.saturating_add(1)),
+            height.max(corner.y.saturating_add(1)),
+        )
+    })
+}
+
+/// One hash map entry per light that was ever touched.
+#[derive(Debug, Default)]
+pub struct HashGrid<S = Brightness> {
+    semantics: S,
+    lights: HashMap<GridPoint, u32>,
+}
+
+impl<S: LightSemantics> HashGrid<S> {
+    pub fn new(semantics: S) -> Self {
+        HashGrid {
+            semantics,
+            lights: HashMap::new(),
+        }
+    }
+}
+
+impl<S: LightSemantics> Grid for HashGrid<S> {
+    fn apply(&mut self, op: &Op) {
+        let mut rect = *op.rect();
+        for grid_point in rect.iter() {
+            let brightness = self.lights.entry(grid_point).or_insert(0);
+            *brightness = self.semantics.apply(op, *brightness);
+        }
+    }
+
+    fn brightness(&self, point: GridPoint) -> u32 {
+        self.lights.get(&point).copied().unwrap_or(0)
+    }
+
+    fn total_brightness(&self) -> usize {
+        self.lights.values().map(|&b| b as usize).sum()
+    }
+}
+
+/// Every light of a `width` by `height` grid, row by row. Lights outside of it are ignored.
+#[derive(Debug, Clone)]
+pub struct DenseGrid<S = Brightness> {
+    semantics: S,
+    width: usize,
+    height: usize,
+    cells: Vec<u32>,
+}
+
+impl<S: LightSemantics> DenseGrid<S> {
+    /// # Panics
+    /// If `width * height` lights can't be allocated.
+    pub fn new(width: usize, height: usize, semantics: S) -> Self {
+        DenseGrid {
+            semantics,
+            width,
+            height,
+            cells: vec![0; width * height],
+        }
+    }
+
+    /// Range of columns and rows of `rect` that are inside the grid.
+    fn clip(&self, rect: &Rect) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let (from, to) = (rect.bottom_left_corner, rect.top_right_corner);
+        let columns = from.x.min(self.width)..to.x.saturating_add(1).min(self.width);
+        let rows = from.y.min(self.height)..to.y.saturating_add(1).min(self.height);
+        (columns, rows)
+    }
+}
+
+impl<S: LightSemantics> Grid for DenseGrid<S> {
+    fn apply(&mut self, op: &Op) {
+        let (columns, rows) = self.clip(op.rect());
+        let semantics = &self.semantics;
+        for row in self
+            .cells
+            .chunks_mut(self.width.max(1))
+            .take(rows.end)
+            .skip(rows.start)
+        {
+            for brightness in &mut row[columns.clone()] {
+                *brightness = semantics.apply(op, *brightness);
+            }
+        }
+    }
+
+    fn brightness(&self, point: GridPoint) -> u32 {
+        if point.x < self.width && point.y < self.height {
+            self.cells[point.y * self.width + point.x]
+        } else {
+            0
+        }
+    }
+
+    fn total_brightness(&self) -> usize {
+        self.cells.iter().map(|&b| b as usize).sum()
+    }
+}
+
+/// Same layout as [`DenseGrid`], with the rows of every operation updated in parallel.
+#[derive(Debug, Clone)]
+pub struct ParallelGrid<S = Brightness>(DenseGrid<S>);
+
+impl<S: LightSemantics> ParallelGrid<S> {
+    pub fn new(width: usize, height: usize, semantics: S) -> Self {
+        ParallelGrid(DenseGrid::new(width, height, semantics))
+    }
+}
+
+impl<S: LightSemantics> Grid for ParallelGrid<S> {
+    fn apply(&mut self, op: &Op) {
+        let (columns, rows) = self.0.clip(op.rect());
+        let width = self.0.width.max(1);
+        let semantics = &self.0.semantics;
+        self.0.cells[rows.start * width..rows.end * width]
+            .par_chunks_mut(width)
+            .for_each(|row| {
+                for brightness in &mut row[columns.clone()] {
+                    *brightness = semantics.apply(op, *brightness);
+                }
+            });
+    }
+
+    fn brightness(&self, point: GridPoint) -> u32 {
+        self.0.brightness(point)
+    }
+
+    fn total_brightness(&self) -> usize {
+        self.0.cells.par_iter().map(|&b| b as usize).sum()
+    }
+}
+
+/// Splits the grid along the edges of every rectangle, so that each operation covers whole
+/// blocks of lights that always share the same brightness.
+///
+/// It can only carry out the operations it was built from.
+#[derive(Debug, Clone)]
+pub struct CompressedGrid<S = Brightness> {
+    semantics: S,
+    /// Sorted first columns of the blocks, the last one being the end of the grid, which can be
+    /// one past `usize::MAX`.
+    xs: Vec<u128>,
+    /// Sorted first rows of the blocks, the last one being the end of the grid.
+    ys: Vec<u128>,
+    blocks: Vec<u32>,
+}
+
+fn edges(ranges: impl Iterator<Item = (usize, usize)>) -> Vec<u128> {
+    let mut edges: Vec<u128> = ranges
+        .flat_map(|(from, to)| [from as u128, to as u128 + 1])
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+    edges
+}
+
+impl<S: LightSemantics> CompressedGrid<S> {
+    pub fn new(ops: &[Op], semantics: S) -> Self {
+        let rects = || ops.iter().map(Op::rect);
+        let xs = edges(rects().map(|r| (r.bottom_left_corner.x, r.top_right_corner.x)));
+        let ys = edges(rects().map(|r| (r.bottom_left_corner.y, r.top_right_corner.y)));
+        let blocks = vec![0; xs.len().saturating_sub(1) * ys.len().saturating_sub(1)];
+        CompressedGrid {
+            semantics,
+            xs,
+            ys,
+            blocks,
+        }
+    }
+
+    fn columns(&self) -> usize {
+        self.xs.len().saturating_sub(1)
+    }
+
+    fn block(edges: &[u128], from: usize, to: usize) -> std::ops::Range<usize> {
+        let start = edges.binary_search(&(from as u128)).expect("unknown edge");
+        let end = edges
+            .binary_search(&(to as u128 + 1))
+            .expect("unknown edge");
+        start..end
+    }
+}
+
+impl<S: LightSemantics> Grid for CompressedGrid<S> {
+    /// # Panics
+    /// If the edges of the rectangle are not edges of the grid.
+    fn apply(&mut self, op: &Op) {
+        let rect = op.rect();
+        let (from, to) = (rect.bottom_left_corner, rect.top_right_corner);
+        let columns = Self::block(&self.xs, from.x, to.x);
+        let rows = Self::block(&self.ys, from.y, to.y);
+        let n = self.columns();
+        for row in rows {
+            for brightness in &mut self.blocks[row * n + columns.start..row * n + columns.end] {
+                *brightness = self.semantics.apply(op, *brightness);
+            }
+        }
+    }
+
+    fn brightness(&self, point: GridPoint) -> u32 {
+        let column = self.xs.partition_point(|&x| x <= point.x as u128);
+        let row = self.ys.partition_point(|&y| y <= point.y as u128);
+        if column == 0 || column == self.xs.len() || row == 0 || row == self.ys.len() {
+            return 0;
+        }
+        self.blocks[(row - 1) * self.columns() + column - 1]
+    }
+
+    fn total_brightness(&self) -> usize {
+        let widths: Vec<u128> = self.xs.windows(2).map(|w| w[1] - w[0]).collect();
+        self.ys
+            .windows(2)
+            .zip(self.blocks.chunks(self.columns().max(1)))
+            .map(|(h, row)| {
+                let area: u128 = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(&b, width)| b as u128 * width)
+                    .sum();
+                area * (h[1] - h[0])
+            })
+            .sum::<u128>()
+            .try_into()
+            .unwrap_or(usize::MAX)
+    }
+}
+
+/// The grid implementations [`crate::total_brightness_with`] can run on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backend {
+    Hash,
+    #[default]
+    Dense,
+    Parallel,
+    Compressed,
+}
+
+impl Backend {
+    pub const ALL: [Backend; 4] = [
+        Backend::Hash,
+        Backend::Dense,
+        Backend::Parallel,
+        Backend::Compressed,
+    ];
+
+    /// A grid, ready for `ops` to be carried out on it. The dense backends fall back to a
+    /// [`CompressedGrid`] when `ops` span more than [`MAX_DENSE_LIGHTS`] lights.
+    pub fn grid<S: LightSemantics + 'static>(self, ops: &[Op], semantics: S) -> Box<dyn Grid> {
+        let (width, height) = bounds(ops);
+        let fits = width
+            .checked_mul(height)
+            .is_some_and(|lights| lights <= MAX_DENSE_LIGHTS);
+        match self {
+            Backend::Hash => Box::new(HashGrid::new(semantics)),
+            Backend::Dense if fits => Box::new(DenseGrid::new(width, height, semantics)),
+            Backend::Parallel if fits => Box::new(ParallelGrid::new(width, height, semantics)),
+            Backend::Dense | Backend::Parallel | Backend::Compressed => {
+                Box::new(CompressedGrid::new(ops, semantics))
+            }
+        }
+    }
+
+    /// Carries out every operation, in order.
+    pub fn run<S: LightSemantics + 'static>(self, ops: &[Op], semantics: S) -> Box<dyn Grid> {
+        let mut grid = self.grid(ops, semantics);
+        for op in ops {
+            grid.apply(op);
+        }
+        grid
+    }
+}
+
+impl ValueEnum for Backend {
+    fn value_variants<'a>() -> &'a [Self] {
+        &Self::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Hash => PossibleValue::new("hash").help("one hash map entry per light"),
+            Self::Dense => PossibleValue::new("dense").help("one integer per light"),
+            Self::Parallel => {
+                PossibleValue::new("parallel").help("dense, updating rows in parallel")
+            }
+            Self::Compressed => {
+                PossibleValue::new("compressed").help("blocks of lights between rectangle edges")
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OnOff;
+    use proptest::prelude::*;
+
+    fn op() -> impl Strategy<Value = Op> {
+        (0..4u8, 0..40usize, 0..40usize, 0..40usize, 0..40usize).prop_map(
+            |(kind, x0, y0, x1, y1)| {
+                let rect = Rect::new(&GridPoint { x: x0, y: y0 }, &GridPoint { x: x1, y: y1 });
+                match kind {
+                    0 => Op::Toggle(rect),
+                    1 => Op::Turn(true, rect),
+                    2 => Op::Turn(false, rect),
+                    _ => Op::Set((x0 % 4) as u32, rect),
+                }
+            },
+        )
+    }
+
+    #[test]
+    fn test_last_column() {
+        let ops: Vec<Op> = vec![
+            "toggle 18446744073709551615,0 through 18446744073709551615,0"
+                .parse()
+                .unwrap(),
+        ];
+        assert_eq!(bounds(&ops), (usize::MAX, 1));
+
+        let mut dense = DenseGrid::new(3, 1, Brightness);
+        dense.apply(&ops[0]);
+        assert_eq!(dense.total_brightness(), 0);
+        for backend in Backend::ALL {
+            let grid = backend.run(&ops, Brightness);
+            assert_eq!(grid.total_brightness(), 2);
+            assert_eq!(
+                grid.brightness(GridPoint {
+                    x: usize::MAX,
+                    y: 0
+                }),
+                2
+            );
+        }
+    }
+
+    #[test]
+    fn test_large_grid() {
+        let ops: Vec<Op> = [
+            "toggle 0,0 through 100000,100000",
+            "turn off 1,1 through 1,1",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        let grid = Backend::default().run(&ops, Brightness);
+        assert_eq!(grid.total_brightness(), 2 * 100_001 * 100_001 - 1);
+        assert_eq!(grid.brightness(GridPoint { x: 1, y: 1 }), 1);
+        assert_eq!(grid.brightness(GridPoint { x: 100_000, y: 0 }), 2);
+    }
+
+    #[test]
+    fn test_compressed_grid() {
+        let ops: Vec<Op> = ["turn on 0,0 through 9,9", "toggle 5,5 through 14,14"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let grid = Backend::Compressed.run(&ops, Brightness);
+        assert_eq!(grid.brightness(GridPoint { x: 0, y: 0 }), 1);
+        assert_eq!(grid.brightness(GridPoint { x: 9, y: 9 }), 3);
+        assert_eq!(grid.brightness(GridPoint { x: 14, y: 14 }), 2);
+        assert_eq!(grid.brightness(GridPoint { x: 15, y: 14 }), 0);
+        assert_eq!(grid.total_brightness(), 100 + 200);
+    }
+
+    proptest! {
+        #[test]
+        fn backends_agree(ops in proptest::collection::vec(op(), 0..20)) {
+            let hash = Backend::Hash.run(&ops, Brightness);
+            let lines: Vec<String> = ops.iter().map(Op::to_string).collect();
+            let expected = crate::total_brightness(lines.into_iter());
+            prop_assert_eq!(hash.total_brightness(), expected);
+            let (width, height) = bounds(&ops);
+            for backend in Backend::ALL {
+                let grid = backend.run(&ops, Brightness);
+                prop_assert_eq!(grid.total_brightness(), expected, "{:?}", backend);
+                for y in 0..=height {
+                    for x in 0..=width {
+                        let point = GridPoint { x, y };
+                        prop_assert_eq!(grid.brightness(point), hash.brightness(point));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn backends_agree_on_off(ops in proptest::collection::vec(op(), 0..20)) {
+            let expected = Backend::Hash.run(&ops, OnOff).total_brightness();
+            for backend in Backend::ALL {
+                prop_assert_eq!(backend.run(&ops, OnOff).total_brightness(), expected);
+            }
+        }
+    }
+}
@@ -1,10 +1,18 @@
+use aoc_common::{Answer, Input, Solution};
 use log::warn;
-use std::collections::HashMap;
 
+mod grid;
 pub mod models; // made pub just because of GridPoint doctest
-use models::{GridPoint, Op};
+use models::{Op, Program};
 
 mod parsers;
+pub mod render;
+mod semantics;
+
+pub use grid::{
+    bounds, Backend, CompressedGrid, DenseGrid, Grid, HashGrid, ParallelGrid, MAX_DENSE_LIGHTS,
+};
+pub use semantics::{Brightness, Clamped, LightSemantics, OnOff, Semantics};
 
 /// Sum all of brightnesses. Very informative description!
 ///
@@ -48,51 +56,107 @@ mod parsers;
 /// assert_eq!(total_brightness(commands), 1);
 /// ```
 pub fn total_brightness(commands: impl Iterator<Item = String>) -> usize {
-    let mut brightness_map: HashMap<GridPoint, usize> = HashMap::new();
-
-    for (i, line) in commands.enumerate() {
-        let op = line.parse::<Op>();
-        match op {
-            Ok(op) => execute(&mut brightness_map, op),
-            Err(_) => {
-                warn!("Ignoring line {}: [{}]", i + 1, line);
-            }
-        }
-    }
+    total_brightness_with(commands, Backend::default())
+}
 
-    brightness_map.values().sum()
+/// Same as [`total_brightness`], on the given grid backend.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::{total_brightness_with, Backend};
+///
+/// for backend in Backend::ALL {
+///     let commands = ["toggle 0,0 through 999,0", "turn off 499,0 through 500,0"];
+///     let commands = commands.into_iter().map(str::to_string);
+///     assert_eq!(total_brightness_with(commands, backend), 1998);
+/// }
+/// ```
+pub fn total_brightness_with(commands: impl Iterator<Item = String>, backend: Backend) -> usize {
+    total_brightness_using(commands, backend, Brightness)
 }
 
-/// Carries out the operation on the light grid, mutating the brightness map as it goes.
-fn execute(brightness_map: &mut HashMap<GridPoint, usize>, op: Op) {
-    match op {
-        Op::Toggle(mut rect) => {
-            for grid_point in rect.iter() {
-                *brightness_map.entry(grid_point).or_insert(0) += 2;
-            }
-        }
-        Op::Turn(true, mut rect) => {
-            for grid_point in rect.iter() {
-                *brightness_map.entry(grid_point).or_insert(0) += 1;
-            }
-        }
-        Op::Turn(false, mut rect) => {
-            for grid_point in rect.iter() {
-                let _ = *brightness_map
-                    .entry(grid_point)
-                    .and_modify(|v| {
-                        *v -= if *v == 0 { 0 } else { 1 };
-                    })
-                    .or_insert(0);
+/// Same as [`total_brightness_with`], following the given rules.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::{total_brightness_using, Backend, Brightness, Clamped};
+///
+/// let commands = ["toggle 0,0 through 1,1", "turn on 0,0 through 0,0"];
+/// let commands = commands.into_iter().map(str::to_string);
+/// let clamped = Clamped { semantics: Brightness, max: 2 };
+/// assert_eq!(total_brightness_using(commands, Backend::Hash, clamped), 8);
+/// ```
+pub fn total_brightness_using<S: LightSemantics + 'static>(
+    commands: impl Iterator<Item = String>,
+    backend: Backend,
+    semantics: S,
+) -> usize {
+    backend
+        .run(&parse_ops(commands), semantics)
+        .total_brightness()
+}
+
+/// Number of lights that are on, following the rules of part one.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::lights_on;
+///
+/// let commands = [
+///     "turn on 0,0 through 999,999",
+///     "toggle 0,0 through 999,0",
+///     "turn off 499,499 through 500,500",
+/// ];
+/// assert_eq!(lights_on(commands.into_iter().map(str::to_string)), 1_000_000 - 1000 - 4);
+/// ```
+pub fn lights_on(commands: impl Iterator<Item = String>) -> usize {
+    total_brightness_using(commands, Backend::default(), OnOff)
+}
+
+/// Parses every command with a [`Program`], leaving out the ones that can't be parsed.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::parse_ops;
+///
+/// let commands = [
+///     "region corner = 0,0 through 1,1  # four lights",
+///     "turn on corner",
+///     "turn of corner",
+///     "toggle circle 5,5 radius 1",
+/// ];
+/// assert_eq!(parse_ops(commands.into_iter().map(str::to_string)).len(), 1 + 3);
+/// ```
+pub fn parse_ops(commands: impl Iterator<Item = String>) -> Vec<Op> {
+    let mut program = Program::new();
+    commands
+        .enumerate()
+        .flat_map(|(i, line)| match program.parse_line(&line) {
+            Ok(ops) => ops,
+            Err(e) => {
+                warn!("Ignoring line {}, {}\n{}", i + 1, e, e.underline(&line));
+                vec![]
             }
-        }
+        })
+        .collect()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Ok(lights_on(input.read_lines()?.into_iter()).to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Ok(total_brightness(input.read_lines()?.into_iter()).to_string())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use models::Rect;
+    use models::{GridPoint, Rect};
 
     #[test]
     fn test_rect() {
@@ -127,6 +191,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rect_iter() {
+        let mut rect = Rect::new(&GridPoint { x: 1, y: 1 }, &GridPoint { x: 2, y: 2 });
+        assert_eq!(rect.iter().count(), 4);
+
+        let top = usize::MAX;
+        let mut rect = Rect::new(
+            &GridPoint {
+                x: top - 1,
+                y: top - 1,
+            },
+            &GridPoint { x: top, y: top },
+        );
+        let points: Vec<GridPoint> = rect.iter().collect();
+        assert_eq!(
+            points,
+            [
+                GridPoint {
+                    x: top - 1,
+                    y: top - 1
+                },
+                GridPoint { x: top, y: top - 1 },
+                GridPoint { x: top - 1, y: top },
+                GridPoint { x: top, y: top },
+            ]
+        );
+    }
+
     #[test]
     fn test_toggle_parse() {
         assert_eq!(
@@ -137,8 +229,10 @@ mod tests {
             })
         );
 
-        assert!("toggle 1,2 through 3,4 ".parse::<Op>().is_err());
-        assert!(" toggle 1,2 through 3,4".parse::<Op>().is_err());
+        assert_eq!(
+            " toggle 1,2 through 3,4 ".parse::<Op>().unwrap(),
+            "toggle 1,2 through 3,4".parse::<Op>().unwrap()
+        );
         assert!("toggle 1,2,3 through 4,5".parse::<Op>().is_err());
         assert!("toggle 1,2,3 through 4,5".parse::<Op>().is_err());
     }
@@ -156,8 +250,10 @@ mod tests {
             )
         );
 
-        assert!("turn on 1,2 through 3,4 ".parse::<Op>().is_err());
-        assert!(" turn on 1,2 through 3,4".parse::<Op>().is_err());
+        assert_eq!(
+            " turn on 1,2 through 3,4 ".parse::<Op>().unwrap(),
+            "turn on 1,2 through 3,4".parse::<Op>().unwrap()
+        );
         assert!("turn on 1,2,3 through 4,5".parse::<Op>().is_err());
         assert!("turn on 1,2,3 through 4,5".parse::<Op>().is_err());
     }
@@ -175,9 +271,106 @@ mod tests {
             )
         );
 
-        assert!("turn on 1,2 through 3,4 ".parse::<Op>().is_err());
-        assert!(" turn on 1,2 through 3,4".parse::<Op>().is_err());
+        assert_eq!(
+            " turn on 1,2 through 3,4 ".parse::<Op>().unwrap(),
+            "turn on 1,2 through 3,4".parse::<Op>().unwrap()
+        );
         assert!("turn on 1,2,3 through 4,5".parse::<Op>().is_err());
         assert!("turn on 1,2,3 through 4,5".parse::<Op>().is_err());
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |line: &str| {
+            let e = line.parse::<Op>().unwrap_err();
+            (line[e.span.clone()].to_string(), e.message)
+        };
+        assert_eq!(
+            error("flip 1,2 through 3,4"),
+            (
+                "flip".to_string(),
+                "expected `toggle`, `turn`, `set` or `region`, found `flip`".to_string()
+            )
+        );
+        assert_eq!(error("turn up 1,2 through 3,4").0, "up");
+        assert_eq!(error("set -1 1,2 through 3,4").0, "-1");
+        assert_eq!(error("toggle 1,2 through 3,x").0, "3,x");
+        assert_eq!(error("toggle 1,2 through 3,4 now").0, "now");
+        assert_eq!(
+            error("toggle 1,2 through 3,4 now").1,
+            "expected the end of the line, found `now`"
+        );
+        assert_eq!(error("toggle 1,2 through").1, "expected a space");
+        assert_eq!(
+            error("toggle 99999999999999999999,0 through 1,1").0,
+            "99999999999999999999,0"
+        );
+        assert_eq!(
+            error("toggle circle 1,1 radius 1").0,
+            "toggle circle 1,1 radius 1"
+        );
+    }
+
+    #[test]
+    fn test_program() {
+        let mut program = Program::new();
+        let mut ops = |line: &str| program.parse_line(line).map(|ops| ops.len());
+        assert_eq!(ops(""), Ok(0));
+        assert_eq!(ops("   # nothing to see here"), Ok(0));
+        assert_eq!(ops("region dot = circle 0,0 radius 0"), Ok(0));
+        assert_eq!(ops("region ring=circle 3,3 radius 3 # 7 rows"), Ok(0));
+        assert_eq!(ops("turn on dot"), Ok(1));
+        assert_eq!(ops("set 7 ring"), Ok(7));
+        assert_eq!(ops("toggle circle 0,0 radius 2"), Ok(3));
+        assert_eq!(ops("toggle polyline 0,0 -> 4,4"), Ok(5));
+        assert_eq!(ops("toggle polyline 0,0->4,0->4,2"), Ok(3));
+        assert_eq!(ops("region dot = 1,1 through 2,2"), Ok(0));
+        assert_eq!(ops("turn off dot"), Ok(1));
+        assert_eq!(
+            ops("toggle circle 1,1 radius").map_err(|e| e.span),
+            Err(24..24)
+        );
+        assert_eq!(
+            ops("toggle circle 0,0 radius 5000000000").map_err(|e| e.to_string()),
+            Err("column 26: expected a smaller radius, found `5000000000`".to_string())
+        );
+        assert_eq!(
+            ops("toggle circle 18446744073709551615,0 radius 1").map_err(|e| e.span),
+            Err(44..45)
+        );
+        assert_eq!(ops("toggle polyline 1,1").map_err(|e| e.span), Err(19..19));
+        assert_eq!(
+            ops("toggle polyline 0,0 -> 1000000000,0").map_err(|e| e.to_string()),
+            Err("column 24: expected a closer point, found `1000000000,0`".to_string())
+        );
+        assert_eq!(
+            ops("toggle polyline 18446744073709551615,0 -> 18446744073709551614,1"),
+            Ok(2)
+        );
+        assert_eq!(
+            ops("toggle polyline 18446744073709551614,5->18446744073709551615,5"),
+            Ok(1)
+        );
+        assert_eq!(
+            ops("toggle polyline 1,1 -> x").map_err(|e| e.span),
+            Err(23..24)
+        );
+        assert_eq!(ops("region 1 = dot").map_err(|e| e.span), Err(7..8));
+        assert_eq!(ops("toggle torus").map_err(|e| e.span), Err(7..12));
+    }
+
+    #[test]
+    fn test_shapes() {
+        let brightness = |lines: &[&str]| total_brightness(lines.iter().map(|s| s.to_string()));
+        assert_eq!(brightness(&["turn on circle 10,10 radius 2"]), 13);
+        assert_eq!(brightness(&["turn on circle 0,0 radius 2"]), 6);
+        assert_eq!(
+            brightness(&["toggle polyline 0,0 -> 3,0 -> 3,3 -> 0,3 -> 0,0"]),
+            24
+        );
+        assert_eq!(
+            brightness(&["set 5 0,0 through 1,1", "turn off 0,0 through 0,0"]),
+            19
+        );
+    }
 }
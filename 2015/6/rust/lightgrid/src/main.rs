@@ -1,6 +1,55 @@
-mod io;
+mod args;
 
-fn main() {
+use std::error::Error;
+use std::fs::File;
+use std::io::BufWriter;
+
+use aoc_common::{Input, Source};
+use args::Args;
+use clap::Parser;
+use lightgrid::models::Op;
+use lightgrid::{bounds, parse_ops, render, Brightness, Clamped, LightSemantics, OnOff, Semantics};
+
+fn run<S: LightSemantics + Clone + 'static>(
+    args: &Args,
+    ops: &[Op],
+    semantics: S,
+) -> Result<usize, Box<dyn Error>> {
+    let grid = args.backend.run(ops, semantics.clone());
+    let size = bounds(ops);
+    if let Some(path) = &args.pgm {
+        render::pgm(grid.as_ref(), size, BufWriter::new(File::create(path)?))?;
+    }
+    if let Some(path) = &args.png {
+        render::png(grid.as_ref(), size, BufWriter::new(File::create(path)?))?;
+    }
+    if let Some(path) = &args.gif {
+        let out = BufWriter::new(File::create(path)?);
+        render::gif(ops, args.backend, semantics, args.delay, out)?;
+    }
+    Ok(grid.total_brightness())
+}
+
+fn clamped<S: LightSemantics + Clone + 'static>(
+    args: &Args,
+    ops: &[Op],
+    semantics: S,
+) -> Result<usize, Box<dyn Error>> {
+    match args.max_brightness {
+        Some(max) => run(args, ops, Clamped { semantics, max }),
+        None => run(args, ops, semantics),
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
     env_logger::init();
-    println!("{}", lightgrid::total_brightness(crate::io::lines()));
+    let args = Args::parse();
+    let lines = Input::new(Source::from_arg(args.input.clone())).read_lines()?;
+    let ops = parse_ops(lines.into_iter());
+    let total = match args.semantics {
+        Semantics::OnOff => clamped(&args, &ops, OnOff),
+        Semantics::Brightness => clamped(&args, &ops, Brightness),
+    }?;
+    println!("{}", total);
+    Ok(())
 }
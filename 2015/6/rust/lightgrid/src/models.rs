@@ -1,12 +1,13 @@
+use std::fmt;
+use std::ops::Range;
 use std::str::FromStr;
 
-use nom::branch::alt;
-use nom::bytes::complete::tag;
 use nom::character::complete::char;
 use nom::combinator::all_consuming;
-use nom::sequence::{separated_pair, tuple};
+use nom::sequence::separated_pair;
 
-use crate::parsers::{parse_grid_point, parse_usize};
+use crate::parsers::{self, parse_usize, Regions, Statement};
+use aoc_common::parsing::Expected;
 
 /// Stores x and y coordinates of a grid that extends only to the first quadrant.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -16,7 +17,7 @@ pub struct GridPoint {
 }
 
 #[derive(Debug)]
-pub struct ParseGridPointError(String);
+pub struct ParseGridPointError(pub String);
 
 impl FromStr for GridPoint {
     type Err = ParseGridPointError;
@@ -43,7 +44,7 @@ impl FromStr for GridPoint {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Rect {
     pub bottom_left_corner: GridPoint,
     pub top_right_corner: GridPoint,
@@ -69,14 +70,15 @@ impl Rect {
 
 pub struct RectIterator<'a> {
     pub rect: &'a Rect,
-    pub current: GridPoint,
+    /// The next light, `None` once the last row is done.
+    pub current: Option<GridPoint>,
 }
 
 impl Rect {
-    pub fn iter(&mut self) -> RectIterator {
+    pub fn iter(&mut self) -> RectIterator<'_> {
         RectIterator {
             rect: self,
-            current: self.bottom_left_corner,
+            current: Some(self.bottom_left_corner),
         }
     }
 }
@@ -85,46 +87,255 @@ impl<'a> Iterator for RectIterator<'a> {
     type Item = GridPoint;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (x, y) = (self.current.x, self.current.y);
+        let grid_point = self.current?;
+        let (x, y) = (grid_point.x, grid_point.y);
         let (max_x, max_y) = (self.rect.top_right_corner.x, self.rect.top_right_corner.y);
 
         if y > max_y {
             return None;
         }
 
-        let grid_point = GridPoint {
-            x: self.current.x,
-            y: self.current.y,
-        };
-
-        let next_grid_point = if x >= max_x {
-            GridPoint {
+        self.current = if x >= max_x {
+            y.checked_add(1).map(|y| GridPoint {
                 x: self.rect.bottom_left_corner.x,
-                y: y + 1,
-            }
+                y,
+            })
         } else {
-            GridPoint { x: x + 1, y }
+            Some(GridPoint { x: x + 1, y })
         };
 
-        self.current = next_grid_point;
-
         Some(grid_point)
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Op {
     Toggle(Rect),
     Turn(bool, Rect),
+    /// Sets the lights to this brightness.
+    Set(u32, Rect),
 }
 
-#[derive(Debug)]
-pub struct ParseOpError(String);
+impl Op {
+    pub fn rect(&self) -> &Rect {
+        match self {
+            Op::Toggle(rect) | Op::Turn(_, rect) | Op::Set(_, rect) => rect,
+        }
+    }
+}
+
+/// Writes the operation back the way it is parsed.
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let action = match self {
+            Op::Toggle(_) => Action::Toggle,
+            Op::Turn(on, _) => Action::Turn(*on),
+            Op::Set(level, _) => Action::Set(*level),
+        };
+        let (from, to) = (self.rect().bottom_left_corner, self.rect().top_right_corner);
+        write!(
+            f,
+            "{} {},{} through {},{}",
+            action, from.x, from.y, to.x, to.y
+        )
+    }
+}
+
+/// What an instruction does to the lights it covers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Toggle,
+    Turn(bool),
+    Set(u32),
+}
+
+impl Action {
+    pub fn op(self, rect: Rect) -> Op {
+        match self {
+            Action::Toggle => Op::Toggle(rect),
+            Action::Turn(on) => Op::Turn(on, rect),
+            Action::Set(level) => Op::Set(level, rect),
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::Toggle => write!(f, "toggle"),
+            Action::Turn(true) => write!(f, "turn on"),
+            Action::Turn(false) => write!(f, "turn off"),
+            Action::Set(level) => write!(f, "set {}", level),
+        }
+    }
+}
+
+/// The lights an instruction covers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Shape {
+    Rect(Rect),
+    /// Lights no further than `radius` from the center, leaving out the ones below or left of
+    /// the grid.
+    Circle {
+        center: GridPoint,
+        radius: usize,
+    },
+    /// Lights on the straight lines between consecutive points.
+    Polyline(Vec<GridPoint>),
+}
+
+/// Points of the line from `p` to `q`, both included.
+fn segment(p: &GridPoint, q: &GridPoint) -> Vec<GridPoint> {
+    let (x1, y1) = (q.x as i128, q.y as i128);
+    let (mut x, mut y) = (p.x as i128, p.y as i128);
+    let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+    let (sx, sy) = ((x1 - x).signum(), (y1 - y).signum());
+    let mut error = dx + dy;
+    let mut points = vec![];
+    loop {
+        points.push(GridPoint {
+            x: x as usize,
+            y: y as usize,
+        });
+        if (x, y) == (x1, y1) {
+            return points;
+        }
+        if 2 * error >= dy {
+            error += dy;
+            x += sx;
+        }
+        if 2 * error <= dx {
+            error += dx;
+            y += sy;
+        }
+    }
+}
+
+impl Shape {
+    /// Rectangles, one row high except for [`Shape::Rect`], that cover every light of the shape
+    /// exactly once.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lightgrid::models::{GridPoint, Shape};
+    ///
+    /// let circle = Shape::Circle { center: GridPoint { x: 5, y: 5 }, radius: 2 };
+    /// let widths: Vec<usize> = circle.rects().iter().map(|r| r.top_right_corner.x + 1 - r.bottom_left_corner.x).collect();
+    /// assert_eq!(widths, [1, 3, 5, 3, 1]);
+    ///
+    /// let polyline = Shape::Polyline(vec![GridPoint { x: 0, y: 0 }, GridPoint { x: 3, y: 0 }, GridPoint { x: 0, y: 0 }]);
+    /// assert_eq!(polyline.rects().len(), 1);
+    /// ```
+    pub fn rects(&self) -> Vec<Rect> {
+        match self {
+            Shape::Rect(rect) => vec![*rect],
+            Shape::Circle { center, radius } => {
+                let r2 = radius.saturating_mul(*radius);
+                (center.y.saturating_sub(*radius)..=center.y.saturating_add(*radius))
+                    .map(|y| {
+                        let half = (r2 - y.abs_diff(center.y).pow(2)).isqrt();
+                        Rect::new(
+                            &GridPoint {
+                                x: center.x.saturating_sub(half),
+                                y,
+                            },
+                            &GridPoint {
+                                x: center.x.saturating_add(half),
+                                y,
+                            },
+                        )
+                    })
+                    .collect()
+            }
+            Shape::Polyline(points) => {
+                let mut cells: Vec<(usize, usize)> = points
+                    .windows(2)
+                    .flat_map(|w| segment(&w[0], &w[1]))
+                    .chain(points.first().copied())
+                    .map(|p| (p.y, p.x))
+                    .collect();
+                cells.sort_unstable();
+                cells.dedup();
+                let mut rects: Vec<Rect> = vec![];
+                for (y, x) in cells {
+                    match rects.last_mut() {
+                        Some(last)
+                            if last.top_right_corner.y == y
+                                && last.top_right_corner.x.checked_add(1) == Some(x) =>
+                        {
+                            last.top_right_corner.x = x;
+                        }
+                        _ => rects.push(Rect::new(&GridPoint { x, y }, &GridPoint { x, y })),
+                    }
+                }
+                rects
+            }
+        }
+    }
+}
+
+/// Why a line couldn't be parsed, with the byte range of the offending token.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseOpError {
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+impl ParseOpError {
+    fn new(line: &str, expected: Expected) -> Self {
+        let start = line.len() - expected.input.len();
+        let token = expected
+            .input
+            .split(char::is_whitespace)
+            .next()
+            .unwrap_or_default();
+        let message = match token {
+            "" => format!("expected {}", expected.what),
+            token => format!("expected {}, found `{}`", expected.what, token),
+        };
+        ParseOpError {
+            span: start..start + token.len(),
+            message,
+        }
+    }
+
+    /// `line` with the offending token underlined.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lightgrid::models::Op;
+    ///
+    /// let line = "toggle 1,2 thru 3,4";
+    /// let error = line.parse::<Op>().unwrap_err();
+    /// assert_eq!(error.to_string(), "column 12: expected `through`, found `thru`");
+    /// assert_eq!(error.underline(line), "toggle 1,2 thru 3,4\n           ^^^^");
+    /// ```
+    pub fn underline(&self, line: &str) -> String {
+        let carets = "^".repeat(self.span.len().max(1));
+        format!("{}\n{}{}", line, " ".repeat(self.span.start), carets)
+    }
+}
+
+impl fmt::Display for ParseOpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "column {}: {}", self.span.start + 1, self.message)
+    }
+}
+
+impl std::error::Error for ParseOpError {}
+
+fn parse_line(line: &str, regions: &Regions) -> Result<Option<Statement>, ParseOpError> {
+    match parsers::line(regions)(line) {
+        Ok((_, statement)) => Ok(statement),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseOpError::new(line, e)),
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers only"),
+    }
+}
 
 impl FromStr for Op {
     type Err = ParseOpError;
 
-    /// Parses this string slice into an Op (operation) instance.
+    /// Parses a single instruction on a rectangle into an Op (operation) instance.
     ///
     /// # Example
     /// ```rust
@@ -133,22 +344,61 @@ impl FromStr for Op {
     ///
     /// let op: Op = "turn on 0,0 through 1,1".parse().unwrap();
     /// assert_eq!(op,Op::Turn(true, Rect { bottom_left_corner: GridPoint { x: 0, y: 0 }, top_right_corner: GridPoint { x: 1, y: 1 } }));
+    /// assert_eq!("  set 3 1,1 through 0,0  # dim".parse::<Op>().unwrap().to_string(), "set 3 0,0 through 1,1");
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, (action, _, from, _, to)) = all_consuming(tuple((
-            alt((tag("toggle"), tag("turn on"), tag("turn off"))),
-            char(' '),
-            parse_grid_point,
-            tag(" through "),
-            parse_grid_point,
-        )))(s)
-        .map_err(|_| ParseOpError("unable to parse line".to_string()))?;
-        let rect = Rect::new(&from, &to);
-        match action {
-            "toggle" => Ok(Op::Toggle(rect)),
-            "turn on" => Ok(Op::Turn(true, rect)),
-            "turn off" => Ok(Op::Turn(false, rect)),
-            _ => Err(ParseOpError(std::format!("invalid token: {}", s))),
+        match parse_line(s, &Regions::new())? {
+            Some(Statement::Instruction(action, Shape::Rect(rect))) => Ok(action.op(rect)),
+            _ => Err(ParseOpError {
+                span: 0..s.len(),
+                message: "expected a single instruction on a rectangle".to_string(),
+            }),
+        }
+    }
+}
+
+/// Parses instructions line by line, remembering the regions defined along the way.
+///
+/// On top of the instructions [`Op`] parses, a line can be
+/// - blank, or a `# comment`, which can also follow any other line,
+/// - `set <n>` followed by a shape, to set the brightness of its lights,
+/// - an instruction on a `circle x,y radius r` or a `polyline x,y -> x,y -> ...`,
+/// - `region <name> = <shape>`, after which `<name>` can be used as a shape.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::models::Program;
+///
+/// let mut program = Program::new();
+/// assert_eq!(program.parse_line("# a plus sign").unwrap(), vec![]);
+/// assert_eq!(program.parse_line("region plus = polyline 0,1 -> 2,1").unwrap(), vec![]);
+/// assert_eq!(program.parse_line("toggle plus").unwrap().len(), 1);
+/// assert_eq!(program.parse_line("set 2 polyline 1,0 -> 1,2").unwrap().len(), 3);
+///
+/// let error = program.parse_line("toggle minus").unwrap_err();
+/// assert_eq!(error.span, 7..12);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Program {
+    regions: Regions,
+}
+
+impl Program {
+    pub fn new() -> Self {
+        Program::default()
+    }
+
+    /// Operations of a single line, none for blank lines, comments and region definitions.
+    pub fn parse_line(&mut self, line: &str) -> Result<Vec<Op>, ParseOpError> {
+        match parse_line(line, &self.regions)? {
+            None => Ok(vec![]),
+            Some(Statement::Region(name, shape)) => {
+                self.regions.insert(name, shape);
+                Ok(vec![])
+            }
+            Some(Statement::Instruction(action, shape)) => {
+                Ok(shape.rects().into_iter().map(|r| action.op(r)).collect())
+            }
         }
     }
 }
@@ -1,11 +1,15 @@
-use nom::bytes::complete::take_till1;
-use nom::character::complete::{digit1, space0};
-use nom::combinator::{map_res, recognize};
-use nom::multi::count;
-use nom::sequence::preceded;
+use std::collections::HashMap;
+
+use aoc_common::parsing::{expect, Expected, Parsed};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_till1};
+use nom::character::complete::{alpha1, alphanumeric1, char, digit1, satisfy, space0, space1};
+use nom::combinator::{cut, eof, map, map_res, not, opt, peek, recognize, rest, value, verify};
+use nom::multi::{count, many0};
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated, tuple};
 use nom::IResult;
 
-use crate::models::GridPoint;
+use crate::models::{Action, GridPoint, Rect, Shape};
 
 pub fn parse_usize(input: &str) -> IResult<&str, usize> {
     map_res(digit1, str::parse)(input)
@@ -15,15 +19,186 @@ pub fn take_word(input: &str) -> IResult<&str, &str> {
     preceded(space0, recognize(take_till1(|c| c == ' ')))(input)
 }
 
-pub fn parse_grid_point(input: &str) -> IResult<&str, GridPoint> {
-    map_res(take_word, str::parse)(input)
-}
-
 #[allow(unused)]
 pub fn take_words<const N: usize>(input: &str) -> IResult<&str, [&str; N]> {
     map_res(count(take_word, N), |words| words.try_into())(input)
 }
 
+/// Regions defined so far, by name.
+pub type Regions = HashMap<String, Shape>;
+
+/// A line of the instruction language, other than blank lines and comments.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Region(String, Shape),
+    Instruction(Action, Shape),
+}
+
+/// `word`, as long as it isn't the beginning of a longer one.
+fn keyword<'a>(word: &'static str) -> impl FnMut(&'a str) -> Parsed<'a, &'a str> {
+    terminated(tag(word), not(satisfy(|c| c.is_alphanumeric() || c == '_')))
+}
+
+fn space(input: &str) -> Parsed<'_, &str> {
+    expect("a space", space1)(input)
+}
+
+fn number(input: &str) -> Parsed<'_, usize> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn point(input: &str) -> Parsed<'_, GridPoint> {
+    expect(
+        "a point like `x,y`",
+        map(separated_pair(number, char(','), number), |(x, y)| {
+            GridPoint { x, y }
+        }),
+    )(input)
+}
+
+fn name(input: &str) -> Parsed<'_, &str> {
+    recognize(pair(alpha1, many0(alt((alphanumeric1, tag("_"))))))(input)
+}
+
+fn rect(input: &str) -> Parsed<'_, Shape> {
+    let through = preceded(space, expect("`through`", keyword("through")));
+    map(
+        pair(point, cut(preceded(through, preceded(space, point)))),
+        |(from, to)| Shape::Rect(Rect::new(&from, &to)),
+    )(input)
+}
+
+/// Largest radius of a circle, which covers one rectangle per row.
+pub const MAX_RADIUS: usize = 1 << 20;
+
+fn circle(input: &str) -> Parsed<'_, Shape> {
+    let (input, center) = preceded(space, point)(input)?;
+    let (input, _) = preceded(space, expect("`radius`", keyword("radius")))(input)?;
+    // every light of the circle must have coordinates that fit
+    let fits = |radius: &usize| {
+        *radius <= MAX_RADIUS
+            && center.x.checked_add(*radius).is_some()
+            && center.y.checked_add(*radius).is_some()
+    };
+    let (input, _) = preceded(space, expect("a radius", peek(number)))(input)?;
+    let (input, radius) = expect("a smaller radius", verify(number, fits))(input)?;
+    Ok((input, Shape::Circle { center, radius }))
+}
+
+/// Longest segment of a polyline, in lights along its longer side.
+pub const MAX_SEGMENT: usize = 1 << 20;
+
+fn polyline(input: &str) -> Parsed<'_, Shape> {
+    let mut arrow = expect("`->`", delimited(space0, tag("->"), space0));
+    let (mut input, first) = preceded(space, point)(input)?;
+    let mut points = vec![first];
+    loop {
+        let remaining = match arrow(input) {
+            Ok((remaining, _)) => remaining,
+            Err(nom::Err::Error(_)) if points.len() > 1 => break,
+            Err(e) => return Err(e),
+        };
+        let last = points[points.len() - 1];
+        let close = |p: &GridPoint| p.x.abs_diff(last.x).max(p.y.abs_diff(last.y)) <= MAX_SEGMENT;
+        let (remaining, _) = cut(peek(point))(remaining)?;
+        let (remaining, next) = cut(expect("a closer point", verify(point, close)))(remaining)?;
+        points.push(next);
+        input = remaining;
+    }
+    Ok((input, Shape::Polyline(points)))
+}
+
+/// A region defined on an earlier line.
+fn region<'a, 'r>(regions: &'r Regions) -> impl FnMut(&'a str) -> Parsed<'a, Shape> + 'r {
+    move |input| {
+        let (remaining, name) = name(input)?;
+        match regions.get(name) {
+            Some(shape) => Ok((remaining, shape.clone())),
+            None => Err(nom::Err::Failure(Expected {
+                input,
+                what: "a region defined on an earlier line",
+            })),
+        }
+    }
+}
+
+/// `x,y through x,y`, `circle x,y radius r`, `polyline x,y -> x,y ...` or a region name.
+pub fn shape<'a, 'r>(regions: &'r Regions) -> impl FnMut(&'a str) -> Parsed<'a, Shape> + 'r {
+    move |input| {
+        expect(
+            "a shape, like `x,y through x,y`, `circle`, `polyline` or a region name",
+            alt((
+                preceded(keyword("circle"), cut(circle)),
+                preceded(keyword("polyline"), cut(polyline)),
+                rect,
+                region(regions),
+            )),
+        )(input)
+    }
+}
+
+fn action(input: &str) -> Parsed<'_, Action> {
+    let on_off = alt((
+        value(Action::Turn(true), keyword("on")),
+        value(Action::Turn(false), keyword("off")),
+    ));
+    let level = map(nom::character::complete::u32, Action::Set);
+    alt((
+        value(Action::Toggle, keyword("toggle")),
+        preceded(
+            keyword("turn"),
+            cut(preceded(space, expect("`on` or `off`", on_off))),
+        ),
+        preceded(
+            keyword("set"),
+            cut(preceded(space, expect("a brightness", level))),
+        ),
+    ))(input)
+}
+
+fn statement<'a, 'r>(regions: &'r Regions) -> impl FnMut(&'a str) -> Parsed<'a, Statement> + 'r {
+    move |input| {
+        let definition = map(
+            preceded(
+                keyword("region"),
+                cut(tuple((
+                    preceded(space, expect("a region name", name)),
+                    delimited(space0, expect("`=`", char('=')), space0),
+                    shape(regions),
+                ))),
+            ),
+            |(name, _, shape)| Statement::Region(name.to_string(), shape),
+        );
+        let instruction = map(
+            pair(action, cut(preceded(space, shape(regions)))),
+            |(action, shape)| Statement::Instruction(action, shape),
+        );
+        expect(
+            "`toggle`, `turn`, `set` or `region`",
+            alt((definition, instruction)),
+        )(input)
+    }
+}
+
+/// A whole line: at most one statement, surrounded by spaces and followed by an optional `#`
+/// comment.
+pub fn line<'a, 'r>(
+    regions: &'r Regions,
+) -> impl FnMut(&'a str) -> Parsed<'a, Option<Statement>> + 'r {
+    move |input| {
+        let nothing = value(None, peek(alt((eof, tag("#")))));
+        let comment = opt(preceded(char('#'), rest));
+        terminated(
+            delimited(
+                space0,
+                alt((nothing, map(statement(regions), Some))),
+                space0,
+            ),
+            pair(comment, expect("the end of the line", eof)),
+        )(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,225 @@
+//! Renders the grid as grayscale images, brighter lights lighter, with row 0 at the bottom.
+//!
+//! Gray levels are scaled so that the brightest light is white. Under [`crate::OnOff`] that
+//! leaves lights that are off black and lights that are on white.
+
+use std::io::{self, Write};
+
+use crate::grid::{bounds, Backend, Grid};
+use crate::models::{GridPoint, Op, Rect};
+use crate::semantics::LightSemantics;
+
+/// Scales brightnesses from `0..=peak` to gray levels.
+fn level(brightness: u32, peak: u32) -> u8 {
+    match peak {
+        0 => 0,
+        _ => (brightness.min(peak) as u64 * 255 / peak as u64) as u8,
+    }
+}
+
+/// Gray levels of the lights in `columns` and `rows`, from the top row down.
+fn pixels(
+    grid: &dyn Grid,
+    columns: std::ops::Range<usize>,
+    rows: std::ops::Range<usize>,
+    peak: u32,
+) -> Vec<u8> {
+    rows.rev()
+        .flat_map(|y| {
+            columns
+                .clone()
+                .map(move |x| level(grid.brightness(GridPoint { x, y }), peak))
+        })
+        .collect()
+}
+
+/// Brightest light of a `width` by `height` grid.
+pub fn peak(grid: &dyn Grid, (width, height): (usize, usize)) -> u32 {
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| grid.brightness(GridPoint { x, y })))
+        .max()
+        .unwrap_or(0)
+}
+
+/// The lights of a `width` by `height` grid.
+fn image(grid: &dyn Grid, (width, height): (usize, usize)) -> Vec<u8> {
+    pixels(grid, 0..width, 0..height, peak(grid, (width, height)))
+}
+
+/// A binary PGM, one pixel per light.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::{render, Backend, OnOff};
+///
+/// let ops = vec!["turn on 0,0 through 1,0".parse().unwrap()];
+/// let grid = Backend::Dense.run(&ops, OnOff);
+/// let mut bytes = Vec::new();
+/// render::pgm(grid.as_ref(), (3, 2), &mut bytes).unwrap();
+/// assert_eq!(bytes, b"P5\n3 2\n255\n\0\0\0\xff\xff\0");
+/// ```
+pub fn pgm(grid: &dyn Grid, size: (usize, usize), mut out: impl Write) -> io::Result<()> {
+    write!(out, "P5\n{} {}\n255\n", size.0, size.1)?;
+    out.write_all(&image(grid, size))?;
+    out.flush()
+}
+
+/// An 8-bit grayscale PNG, one pixel per light.
+pub fn png(
+    grid: &dyn Grid,
+    size: (usize, usize),
+    out: impl Write,
+) -> Result<(), png::EncodingError> {
+    let mut encoder = png::Encoder::new(out, size.0 as u32, size.1 as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&image(grid, size))
+}
+
+/// Columns and rows of `rect` that fit in a `width` by `height` grid.
+fn clip(
+    rect: &Rect,
+    (width, height): (usize, usize),
+) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+    let (from, to) = (rect.bottom_left_corner, rect.top_right_corner);
+    let columns = from.x.min(width)..to.x.saturating_add(1).min(width);
+    let rows = from.y.min(height)..to.y.saturating_add(1).min(height);
+    (columns, rows)
+}
+
+fn too_large() -> gif::EncodingError {
+    io::Error::new(io::ErrorKind::InvalidInput, "grid too large for a GIF").into()
+}
+
+/// One frame of `pixels`, rows of `width` gray levels, with its top left corner at `left`,
+/// `top`, left in place for the next one.
+fn frame((left, top): (u16, u16), width: u16, pixels: &[u8], delay: u16) -> gif::Frame<'_> {
+    gif::Frame {
+        delay,
+        dispose: gif::DisposalMethod::Keep,
+        left,
+        top,
+        width,
+        height: (pixels.len() / width.max(1) as usize) as u16,
+        buffer: pixels.into(),
+        ..gif::Frame::default()
+    }
+}
+
+/// An animated GIF of the grid, starting dark, with one frame per operation shown for `delay`
+/// hundredths of a second.
+///
+/// The operations are carried out twice, the first time to find how bright the grid gets. Every
+/// frame after the first one only covers the rectangle of its operation.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::{render, Backend, Brightness};
+///
+/// let ops = vec!["toggle 0,0 through 9,9".parse().unwrap()];
+/// let mut bytes = Vec::new();
+/// render::gif(&ops, Backend::Dense, Brightness, 10, &mut bytes).unwrap();
+/// assert!(bytes.starts_with(b"GIF89a\x0a\x00\x0a\x00"));
+/// assert_eq!(bytes.last(), Some(&0x3b));
+/// ```
+pub fn gif<S: LightSemantics + Clone + 'static>(
+    ops: &[Op],
+    backend: Backend,
+    semantics: S,
+    delay: u16,
+    out: impl Write,
+) -> Result<(), gif::EncodingError> {
+    let size = bounds(ops);
+    let (width, height) = size;
+    let mut grid = backend.grid(ops, semantics.clone());
+    let mut peak = 0;
+    for op in ops {
+        grid.apply(op);
+        let (columns, rows) = clip(op.rect(), size);
+        for y in rows {
+            for x in columns.clone() {
+                peak = peak.max(grid.brightness(GridPoint { x, y }));
+            }
+        }
+    }
+
+    let dimension = |n: usize| u16::try_from(n).map_err(|_| too_large());
+    let grays: Vec<u8> = (0..=255).flat_map(|gray| [gray; 3]).collect();
+    let mut encoder = gif::Encoder::new(out, dimension(width)?, dimension(height)?, &grays)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    let mut grid = backend.grid(ops, semantics);
+    let dark = vec![0; width * height];
+    encoder.write_frame(&frame((0, 0), dimension(width)?, &dark, delay))?;
+    for op in ops {
+        grid.apply(op);
+        let (columns, rows) = clip(op.rect(), size);
+        let corner = (dimension(columns.start)?, dimension(height - rows.end)?);
+        let frame_width = dimension(columns.len())?;
+        let pixels = pixels(grid.as_ref(), columns, rows, peak);
+        encoder.write_frame(&frame(corner, frame_width, &pixels, delay))?;
+    }
+    encoder.into_inner()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Brightness, OnOff};
+
+    fn ops(lines: &[&str]) -> Vec<Op> {
+        lines.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_image() {
+        let ops = ops(&["toggle 0,0 through 1,1", "turn on 1,1 through 1,1"]);
+        let grid = Backend::Hash.run(&ops, Brightness);
+        assert_eq!(peak(grid.as_ref(), (2, 2)), 3);
+        assert_eq!(image(grid.as_ref(), (2, 2)), [170, 255, 170, 170]);
+
+        let grid = Backend::Hash.run(&ops, OnOff);
+        assert_eq!(image(grid.as_ref(), (2, 2)), [255, 255, 255, 255]);
+        assert_eq!(image(grid.as_ref(), (3, 1)), [255, 255, 0]);
+    }
+
+    #[test]
+    fn test_png() {
+        let ops = ops(&["turn on 1,0 through 1,0"]);
+        let grid = Backend::Dense.run(&ops, OnOff);
+        let mut bytes = Vec::new();
+        png(grid.as_ref(), (2, 2), &mut bytes).unwrap();
+
+        let decoder = ::png::Decoder::new(bytes.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).unwrap();
+        assert_eq!((info.width, info.height), (2, 2));
+        assert_eq!(&buffer[..info.buffer_size()], &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_gif_frames() {
+        let ops = ops(&["turn on 0,0 through 3,1", "toggle 2,1 through 3,3"]);
+        let mut bytes = Vec::new();
+        gif(&ops, Backend::Compressed, OnOff, 5, &mut bytes).unwrap();
+        let mut options = ::gif::DecodeOptions::new();
+        options.set_color_output(::gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(bytes.as_slice()).unwrap();
+        let mut frames = vec![];
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            let corner = (frame.left, frame.top, frame.width, frame.height);
+            frames.push((corner, frame.delay, frame.buffer.to_vec()));
+        }
+        // the whole grid, then each rectangle with its top row measured from the top
+        assert_eq!(
+            frames,
+            [
+                ((0, 0, 4, 4), 5, vec![0; 16]),
+                ((0, 2, 4, 2), 5, vec![255; 8]),
+                ((2, 0, 2, 3), 5, vec![255, 255, 255, 255, 0, 0]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use clap::{builder::PossibleValue, ValueEnum};
+
+use crate::models::Op;
+
+/// What each kind of instruction does to the brightness of a light.
+///
+/// Custom rules only need to implement this trait to run on any grid backend.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::{Backend, LightSemantics};
+///
+/// /// Turning a light off halves its brightness.
+/// #[derive(Clone)]
+/// struct Dimmer;
+///
+/// impl LightSemantics for Dimmer {
+///     fn toggle(&self, brightness: u32) -> u32 { brightness + 2 }
+///     fn turn_on(&self, brightness: u32) -> u32 { brightness + 1 }
+///     fn turn_off(&self, brightness: u32) -> u32 { brightness / 2 }
+/// }
+///
+/// let ops = ["toggle 0,0 through 1,1", "toggle 0,0 through 0,1", "turn off 0,0 through 1,1"];
+/// let ops: Vec<_> = ops.iter().map(|s| s.parse().unwrap()).collect();
+/// assert_eq!(Backend::Dense.run(&ops, Dimmer).total_brightness(), 2 * 2 + 2 * 1);
+/// ```
+pub trait LightSemantics: Send + Sync {
+    fn toggle(&self, brightness: u32) -> u32;
+
+    fn turn_on(&self, brightness: u32) -> u32;
+
+    fn turn_off(&self, brightness: u32) -> u32;
+
+    /// Brightness of a light set to `level`, which is `level` itself unless overridden.
+    fn set(&self, _brightness: u32, level: u32) -> u32 {
+        level
+    }
+
+    /// Brightness of a light after `op` is carried out on it.
+    fn apply(&self, op: &Op, brightness: u32) -> u32 {
+        match op {
+            Op::Toggle(_) => self.toggle(brightness),
+            Op::Turn(true, _) => self.turn_on(brightness),
+            Op::Turn(false, _) => self.turn_off(brightness),
+            Op::Set(level, _) => self.set(brightness, *level),
+        }
+    }
+}
+
+/// Part one: lights are either off (0) or on (1).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OnOff;
+
+impl LightSemantics for OnOff {
+    fn toggle(&self, brightness: u32) -> u32 {
+        (brightness == 0) as u32
+    }
+
+    fn turn_on(&self, _: u32) -> u32 {
+        1
+    }
+
+    fn turn_off(&self, _: u32) -> u32 {
+        0
+    }
+
+    /// Any brightness other than 0 turns the light on.
+    fn set(&self, _: u32, level: u32) -> u32 {
+        level.min(1)
+    }
+}
+
+/// Part two: toggle adds 2, turning on adds 1 and turning off takes 1 away, down to 0.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Brightness;
+
+impl LightSemantics for Brightness {
+    fn toggle(&self, brightness: u32) -> u32 {
+        brightness + 2
+    }
+
+    fn turn_on(&self, brightness: u32) -> u32 {
+        brightness + 1
+    }
+
+    fn turn_off(&self, brightness: u32) -> u32 {
+        brightness.saturating_sub(1)
+    }
+}
+
+/// Caps the brightness given by other semantics at `max`.
+///
+/// # Example
+/// ```rust
+/// use lightgrid::{Brightness, Clamped, LightSemantics};
+///
+/// let clamped = Clamped { semantics: Brightness, max: 3 };
+/// assert_eq!(clamped.toggle(2), 3);
+/// assert_eq!(clamped.turn_off(3), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Clamped<S> {
+    pub semantics: S,
+    pub max: u32,
+}
+
+impl<S: LightSemantics> LightSemantics for Clamped<S> {
+    fn toggle(&self, brightness: u32) -> u32 {
+        self.semantics.toggle(brightness).min(self.max)
+    }
+
+    fn turn_on(&self, brightness: u32) -> u32 {
+        self.semantics.turn_on(brightness).min(self.max)
+    }
+
+    fn turn_off(&self, brightness: u32) -> u32 {
+        self.semantics.turn_off(brightness).min(self.max)
+    }
+
+    fn set(&self, brightness: u32, level: u32) -> u32 {
+        self.semantics.set(brightness, level).min(self.max)
+    }
+}
+
+/// The semantics that can be picked from the command line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Semantics {
+    OnOff,
+    #[default]
+    Brightness,
+}
+
+impl ValueEnum for Semantics {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::OnOff, Self::Brightness]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::OnOff => PossibleValue::new("on-off").help("part one, lights are on or off"),
+            Self::Brightness => PossibleValue::new("brightness").help("part two, lights dim"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_off() {
+        assert_eq!(OnOff.toggle(0), 1);
+        assert_eq!(OnOff.toggle(1), 0);
+        assert_eq!(OnOff.turn_on(1), 1);
+        assert_eq!(OnOff.turn_off(1), 0);
+        assert_eq!(OnOff.set(0, 5), 1);
+        assert_eq!(OnOff.set(1, 0), 0);
+    }
+
+    #[test]
+    fn test_brightness() {
+        assert_eq!(Brightness.toggle(0), 2);
+        assert_eq!(Brightness.turn_on(2), 3);
+        assert_eq!(Brightness.turn_off(0), 0);
+        assert_eq!(Brightness.turn_off(3), 2);
+    }
+}
@@ -0,0 +1,25 @@
+use circuits::{generate, signal_map, Circuit};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::prelude::*;
+
+fn evaluation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("signals");
+    for wires in [1_000, 10_000, 100_000] {
+        let connections = generate::netlist::<u16>(wires, &mut StdRng::seed_from_u64(2015));
+        group.throughput(Throughput::Elements(wires as u64));
+        group.bench_with_input(
+            BenchmarkId::new("signal_map", wires),
+            &connections,
+            |b, connections| b.iter(|| signal_map(black_box(connections.clone()).into_iter())),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("circuit", wires),
+            &connections,
+            |b, connections| b.iter(|| Circuit::new(black_box(connections.clone())).signals()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, evaluation);
+criterion_main!(benches);
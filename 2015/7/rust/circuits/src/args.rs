@@ -0,0 +1,46 @@
+use circuits::Width;
+use clap::Parser;
+
+/// Simulates Bobby Tables' circuit
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// File containing the connections, stdin if missing or `-`
+    pub input: Option<String>,
+
+    /// Only evaluate this wire and the ones it depends on
+    #[arg(long, env = "WIRE")]
+    pub wire: Option<String>,
+
+    /// Integers the signals are carried on
+    #[arg(long, value_enum, default_value_t = Width::default())]
+    pub width: Width,
+
+    /// Signals forced onto wires, like `b=3176,c=0`
+    #[arg(long = "override", env = "OVERRIDE", value_delimiter = ',', value_parser = parse_override)]
+    pub overrides: Vec<(String, String)>,
+
+    /// Don't let more than one connection drive the same wire
+    #[arg(long, env = "STRICT")]
+    pub strict: bool,
+
+    /// Print the circuit as a Graphviz DOT graph, with its signals if they can be evaluated
+    #[arg(long)]
+    pub dot: bool,
+
+    /// Print a random circuit of this many wires instead of reading one
+    #[arg(long, value_name = "WIRES")]
+    pub generate: Option<usize>,
+
+    /// Seed for the random circuit
+    #[arg(long, requires = "generate")]
+    pub seed: Option<u64>,
+}
+
+/// Splits `wire=value`, leaving the value to be parsed once the width is known.
+fn parse_override(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((wire, value)) => Ok((wire.trim().to_string(), value.trim().to_string())),
+        None => Err(format!("expected wire=value, found {:?}", s)),
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{CircuitError, Connection, ConnectionGraph, Expr, Node, SignalMap, Wire};
+use crate::word::Word;
+use crate::{evaluate, resolve_dependencies};
+
+/// A circuit that only evaluates the wires it is asked about, remembering their signals until
+/// one of the connections they depend on changes.
+///
+/// # Example
+/// ```rust
+/// use circuits::Circuit;
+///
+/// let mut circuit: Circuit = ["123 -> x", "x AND 7 -> a", "456 -> b"]
+///     .iter()
+///     .map(|s| s.parse().unwrap())
+///     .collect();
+/// assert_eq!(circuit.evaluate("a"), Ok(3));
+/// assert_eq!(circuit.cached(), 2);
+///
+/// let mut circuit = circuit.with_override("x", 12);
+/// assert_eq!(circuit.evaluate("a"), Ok(4));
+/// ```
+#[derive(Debug, Default)]
+pub struct Circuit<W = u16> {
+    graph: ConnectionGraph<W>,
+    /// The wires each wire feeds into.
+    dependents: HashMap<Wire, HashSet<Wire>>,
+    signals: SignalMap<W>,
+}
+
+impl<W: Word> Circuit<W> {
+    pub fn new(connections: impl IntoIterator<Item = Connection<W>>) -> Self {
+        let mut circuit = Circuit::default();
+        for connection in connections {
+            circuit.connect(connection);
+        }
+        circuit
+    }
+
+    /// Drives the target of `connection` with its source instead of whatever drove it before.
+    /// Only the signals that depend on the target are forgotten.
+    pub fn connect(&mut self, connection: Connection<W>) {
+        let target = connection.target;
+        let dependencies = resolve_dependencies(&connection.source);
+        for dependency in &dependencies {
+            self.dependents
+                .entry(dependency.clone())
+                .or_default()
+                .insert(target.clone());
+        }
+        let node = Node {
+            dependencies,
+            expr: connection.source,
+        };
+        if let Some(old) = self.graph.insert(target.clone(), node) {
+            for dependency in old.dependencies {
+                if !self.graph[&target].dependencies.contains(&dependency) {
+                    if let Some(dependents) = self.dependents.get_mut(&dependency) {
+                        dependents.remove(&target);
+                    }
+                }
+            }
+        }
+        self.invalidate(&target);
+    }
+
+    /// Same circuit, with `wire` carrying `value` whatever it was connected to.
+    pub fn with_override(mut self, wire: &str, value: W) -> Self {
+        self.connect(Connection {
+            source: Expr::Value(value),
+            target: Wire::from(wire),
+        });
+        self
+    }
+
+    /// Forgets the signal on `wire` and on every wire downstream of it.
+    fn invalidate(&mut self, wire: &str) {
+        let mut stale = vec![wire.to_string()];
+        while let Some(wire) = stale.pop() {
+            // wires downstream of one that was never evaluated can't have been evaluated either
+            if self.signals.remove(&wire).is_some() {
+                if let Some(dependents) = self.dependents.get(&wire) {
+                    stale.extend(dependents.iter().cloned());
+                }
+            }
+        }
+    }
+
+    /// Signal on `wire`, evaluating the wires it depends on if needed. Fails if the wire, or
+    /// one it depends on, isn't driven by anything or depends on itself.
+    pub fn evaluate(&mut self, wire: &str) -> Result<W, CircuitError> {
+        let mut stack = vec![(wire.to_string(), false)];
+        let mut pending = HashSet::new();
+        while let Some((wire, ready)) = stack.pop() {
+            if self.signals.contains_key(&wire) {
+                continue;
+            }
+            let Some(node) = self.graph.get(&wire) else {
+                return Err(self.undefined(wire));
+            };
+            if ready {
+                let value = evaluate(&self.signals, &node.expr);
+                pending.remove(&wire);
+                self.signals.insert(wire, value);
+                continue;
+            }
+            // wires stay pending until the ones they depend on are evaluated, so this is a cycle
+            if !pending.insert(wire.clone()) {
+                // the wires waiting on their dependencies, in order, are the way to this one
+                let mut path: Vec<Wire> = stack
+                    .iter()
+                    .filter(|(_, ready)| *ready)
+                    .map(|(wire, _)| wire.clone())
+                    .skip_while(|w| *w != wire)
+                    .collect();
+                path.push(wire);
+                return Err(CircuitError::Cycle { path });
+            }
+            let missing: Vec<_> = node
+                .dependencies
+                .iter()
+                .filter(|dependency| !self.signals.contains_key(*dependency))
+                .map(|dependency| (dependency.clone(), false))
+                .collect();
+            stack.push((wire, true));
+            stack.extend(missing);
+        }
+        Ok(self.signals[wire])
+    }
+
+    fn undefined(&self, name: Wire) -> CircuitError {
+        let mut referenced_by: Vec<Wire> = self
+            .dependents
+            .get(&name)
+            .map(|wires| wires.iter().cloned().collect())
+            .unwrap_or_default();
+        referenced_by.sort();
+        CircuitError::UndefinedWire {
+            name,
+            referenced_by,
+        }
+    }
+
+    /// Signals on every wire.
+    pub fn signals(&mut self) -> Result<SignalMap<W>, CircuitError> {
+        let mut wires: Vec<Wire> = self.graph.keys().cloned().collect();
+        wires.sort();
+        for wire in wires {
+            self.evaluate(&wire)?;
+        }
+        Ok(self.signals.clone())
+    }
+
+    /// Number of wires whose signal is known.
+    pub fn cached(&self) -> usize {
+        self.signals.len()
+    }
+}
+
+impl<W: Word> FromIterator<Connection<W>> for Circuit<W> {
+    fn from_iter<T: IntoIterator<Item = Connection<W>>>(connections: T) -> Self {
+        Circuit::new(connections)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circuit(lines: &[&str]) -> Circuit {
+        lines.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_lazy() {
+        let mut circuit = circuit(&["1 -> x", "x OR 2 -> y", "y LSHIFT 1 -> z", "5 -> w"]);
+        assert_eq!(circuit.evaluate("y"), Ok(3));
+        assert_eq!(circuit.cached(), 2);
+        assert_eq!(circuit.evaluate("z"), Ok(6));
+        assert_eq!(circuit.cached(), 3);
+        assert_eq!(
+            circuit.evaluate("nope"),
+            Err(CircuitError::UndefinedWire {
+                name: "nope".to_string(),
+                referenced_by: vec![]
+            })
+        );
+    }
+
+    #[test]
+    fn test_override() {
+        let mut circuit = circuit(&["1 -> x", "x OR 2 -> y", "y LSHIFT 1 -> z", "5 -> w"]);
+        assert_eq!(circuit.signals().map(|s| s.len()), Ok(4));
+
+        let mut circuit = circuit.with_override("y", 8);
+        assert_eq!(circuit.cached(), 2);
+        assert_eq!(circuit.evaluate("z"), Ok(16));
+
+        circuit.connect("w AND 4 -> y".parse().unwrap());
+        assert_eq!(circuit.evaluate("z"), Ok(8));
+        circuit.connect("1 -> w".parse().unwrap());
+        assert_eq!(circuit.evaluate("z"), Ok(0));
+        assert_eq!(circuit.evaluate("x"), Ok(1));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut circuit = circuit(&["1 -> x", "x AND z -> y", "y -> z", "x -> a"]);
+        let cycle = |wires: &[&str]| CircuitError::Cycle {
+            path: wires.iter().map(|s| s.to_string()).collect(),
+        };
+        assert_eq!(circuit.evaluate("z"), Err(cycle(&["z", "y", "z"])));
+        assert_eq!(circuit.evaluate("a"), Ok(1));
+        assert_eq!(circuit.signals(), Err(cycle(&["y", "z", "y"])));
+
+        let mut circuit = circuit.with_override("z", 3);
+        assert_eq!(circuit.evaluate("y"), Ok(1));
+        assert!(circuit.evaluate("missing").is_err());
+
+        let mut circuit: Circuit<i8> = Circuit::new(["-128 -> x".parse().unwrap()]);
+        circuit.connect("x SUB 1 RSHIFT 7 -> y".parse().unwrap());
+        assert_eq!(circuit.evaluate("y"), Ok(0));
+        assert_eq!(circuit.with_override("x", -1).evaluate("y"), Ok(-1));
+        assert_eq!(
+            Circuit::<u16>::new(["b -> a".parse().unwrap()]).evaluate("a"),
+            Err(CircuitError::UndefinedWire {
+                name: "b".to_string(),
+                referenced_by: vec!["a".to_string()]
+            })
+        );
+    }
+}
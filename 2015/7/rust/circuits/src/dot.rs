@@ -0,0 +1,163 @@
+//! Draws a connection graph with Graphviz: wires are ellipses, gates are shaped after what they
+//! do and constants are plain text. Known signals label the wires and the outputs of gates.
+
+use std::fmt::Write;
+
+use crate::models::{ConnectionGraph, Expr, SignalMap};
+use crate::word::Word;
+use crate::{evaluate, resolve_dependencies};
+
+fn shape(gate: &str) -> &'static str {
+    match gate {
+        "AND" | "NAND" => "invhouse",
+        "OR" => "invtrapezium",
+        "XOR" => "hexagon",
+        "NOT" => "invtriangle",
+        "LSHIFT" | "RSHIFT" => "cds",
+        _ => "circle",
+    }
+}
+
+struct Dot<'a, W> {
+    out: String,
+    signals: Option<&'a SignalMap<W>>,
+    nodes: usize,
+}
+
+impl<W: Word> Dot<'_, W> {
+    /// Id of the node `expr` comes out of, adding nodes for gates and constants.
+    fn node(&mut self, expr: &Expr<W>) -> String {
+        match expr {
+            Expr::Symbol(wire) => format!("{:?}", wire),
+            Expr::Value(value) => {
+                self.nodes += 1;
+                let id = format!("c{}", self.nodes);
+                let _ = writeln!(
+                    self.out,
+                    "    {} [shape=plaintext, label=\"{}\"];",
+                    id, value
+                );
+                id
+            }
+            _ => {
+                self.nodes += 1;
+                let id = format!("g{}", self.nodes);
+                let gate = expr.gate().unwrap_or_default();
+                let _ = writeln!(
+                    self.out,
+                    "    {} [shape={}, label=\"{}\"];",
+                    id,
+                    shape(gate),
+                    gate
+                );
+                for input in expr.inputs() {
+                    let from = self.node(input);
+                    let label = self.label(input);
+                    let _ = writeln!(self.out, "    {} -> {}{};", from, id, label);
+                }
+                id
+            }
+        }
+    }
+
+    /// Edge attributes showing the signal coming out of a gate, if it is known.
+    fn label(&self, expr: &Expr<W>) -> String {
+        let Some(signals) = self.signals else {
+            return String::new();
+        };
+        let known = resolve_dependencies(expr)
+            .iter()
+            .all(|wire| signals.contains_key(wire));
+        match expr.gate() {
+            Some(_) if known => format!(" [label=\"{}\"]", evaluate(signals, expr)),
+            _ => String::new(),
+        }
+    }
+}
+
+/// The graph in Graphviz DOT, wires in alphabetical order. Wires that are read from but not
+/// driven are dashed.
+///
+/// # Example
+/// ```rust
+/// use circuits::models::Connection;
+/// use circuits::{dot, from_connections, signal_map};
+///
+/// let lines = ["123 -> x", "x AND 7 -> a"];
+/// let graph = from_connections(lines.iter().map(|s| s.parse::<Connection>().unwrap()));
+/// let signals = signal_map(lines.iter().map(|s| s.parse().unwrap())).unwrap();
+/// let dot = dot::to_dot(&graph, Some(&signals));
+/// assert!(dot.contains(r#""a" [shape=ellipse, label="a\n3"];"#));
+/// assert!(dot.contains(r#"g1 [shape=invhouse, label="AND"];"#));
+/// assert!(dot.contains(r#"g1 -> "a" [label="3"];"#));
+/// ```
+pub fn to_dot<W: Word>(graph: &ConnectionGraph<W>, signals: Option<&SignalMap<W>>) -> String {
+    let mut dot = Dot {
+        out: String::from("digraph circuit {\n    rankdir=LR;\n"),
+        signals,
+        nodes: 0,
+    };
+    let mut wires: Vec<_> = graph.keys().collect();
+    wires.sort();
+    let mut undriven: Vec<_> = graph
+        .values()
+        .flat_map(|node| node.dependencies.iter())
+        .filter(|wire| !graph.contains_key(*wire))
+        .collect();
+    undriven.sort();
+    undriven.dedup();
+
+    for wire in &wires {
+        let label = match signals.and_then(|signals| signals.get(*wire)) {
+            Some(value) => format!("{}\\n{}", wire, value),
+            None => wire.to_string(),
+        };
+        let _ = writeln!(
+            dot.out,
+            "    {:?} [shape=ellipse, label=\"{}\"];",
+            wire, label
+        );
+    }
+    for wire in undriven {
+        let _ = writeln!(dot.out, "    {:?} [shape=ellipse, style=dashed];", wire);
+    }
+    for wire in wires {
+        let expr = &graph[wire].expr;
+        let from = dot.node(expr);
+        let label = dot.label(expr);
+        let _ = writeln!(dot.out, "    {} -> {:?}{};", from, wire, label);
+    }
+    dot.out.push_str("}\n");
+    dot.out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::from_connections;
+    use crate::models::Connection;
+
+    #[test]
+    fn test_to_dot() {
+        let lines = ["1 -> x", "NOT (x OR y) -> z"];
+        let graph = from_connections(lines.iter().map(|s| s.parse::<Connection>().unwrap()));
+        assert_eq!(
+            to_dot(&graph, None),
+            r#"digraph circuit {
+    rankdir=LR;
+    "x" [shape=ellipse, label="x"];
+    "z" [shape=ellipse, label="z"];
+    "y" [shape=ellipse, style=dashed];
+    c1 [shape=plaintext, label="1"];
+    c1 -> "x";
+    g2 [shape=invtriangle, label="NOT"];
+    g3 [shape=invtrapezium, label="OR"];
+    "x" -> g3;
+    "y" -> g3;
+    g3 -> g2;
+    g2 -> "z";
+}
+"#
+        );
+    }
+}
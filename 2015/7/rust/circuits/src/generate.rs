@@ -0,0 +1,126 @@
+//! Random circuits, for fuzzing and benchmarking.
+
+use rand::distributions::{Distribution, Standard};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::models::{Connection, Expr, Wire};
+use crate::word::Word;
+
+/// Name of the `n`th wire: `a` to `z`, then `aa`, `ab` and so on.
+fn name(mut n: usize) -> Wire {
+    let mut name = vec![];
+    loop {
+        name.push(b'a' + (n % 26) as u8);
+        n /= 26;
+        if n == 0 {
+            break;
+        }
+        n -= 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Some earlier wire, or now and then a value.
+fn operand<W>(n: usize, rng: &mut impl Rng) -> Box<Expr<W>>
+where
+    Standard: Distribution<W>,
+{
+    Box::new(match rng.gen_ratio(1, 8) {
+        true => Expr::Value(rng.gen()),
+        false => Expr::Symbol(name(rng.gen_range(0..n))),
+    })
+}
+
+/// Source of the `n`th wire, only reading from the wires before it.
+fn source<W: Word>(n: usize, rng: &mut impl Rng) -> Expr<W>
+where
+    Standard: Distribution<W>,
+{
+    // the first wires need something to start from
+    if n < 2 {
+        return Expr::Value(rng.gen());
+    }
+    let left = operand(n, rng);
+    let right = operand(n, rng);
+    // mostly shifts that keep some of the bits
+    let shift = Box::new(Expr::Value(W::truncate(
+        rng.gen_range(0..W::BITS as u64 + 4),
+    )));
+    match rng.gen_range(0..14) {
+        0 => Expr::Value(rng.gen()),
+        1 => *left,
+        2 => Expr::Not(left),
+        3 => Expr::And(left, right),
+        4 => Expr::Or(left, right),
+        5 => Expr::LShift(left, shift),
+        6 => Expr::RShift(left, shift),
+        7 => Expr::Xor(left, right),
+        8 => Expr::Nand(left, right),
+        9 => Expr::Add(left, right),
+        10 => Expr::Sub(left, right),
+        11 => Expr::Mul(left, right),
+        12 => Expr::Div(left, right),
+        _ => Expr::Mod(left, right),
+    }
+}
+
+/// A circuit of `wires` wires, each driven exactly once and never depending on itself, listed
+/// in no particular order.
+///
+/// # Example
+/// ```rust
+/// use circuits::{generate, signal_map};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let connections = generate::netlist::<u16>(100, &mut StdRng::seed_from_u64(7));
+/// assert_eq!(connections.len(), 100);
+/// assert_eq!(signal_map(connections.into_iter()).map(|s| s.len()), Ok(100));
+/// ```
+pub fn netlist<W: Word>(wires: usize, rng: &mut impl Rng) -> Vec<Connection<W>>
+where
+    Standard: Distribution<W>,
+{
+    let mut connections: Vec<Connection<W>> = (0..wires)
+        .map(|n| Connection {
+            source: source(n, rng),
+            target: name(n),
+        })
+        .collect();
+    connections.shuffle(rng);
+    connections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{signal_map, Circuit};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_name() {
+        let names: Vec<Wire> = [0, 25, 26, 27, 701, 702].into_iter().map(name).collect();
+        assert_eq!(names, ["a", "z", "aa", "ab", "zz", "aaa"]);
+    }
+
+    /// Makes sure the circuit reads back the same and both ways of evaluating it agree.
+    fn check<W: Word>(connections: Vec<Connection<W>>) {
+        let lines: Vec<String> = connections.iter().map(|c| c.to_string()).collect();
+        let parsed = crate::parse_connections(lines.into_iter()).unwrap();
+        assert_eq!(parsed, connections);
+
+        let expected = signal_map(connections.clone().into_iter()).unwrap();
+        assert_eq!(Circuit::new(connections).signals(), Ok(expected));
+    }
+
+    #[test]
+    fn test_netlists() {
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            check(netlist::<u16>(200, &mut rng));
+            check(netlist::<u8>(200, &mut rng));
+            check(netlist::<i64>(200, &mut rng));
+        }
+    }
+}
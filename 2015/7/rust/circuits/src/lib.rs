@@ -1,26 +1,113 @@
+mod circuit;
+pub mod dot;
+pub mod generate;
 pub mod models;
 mod parsers;
+pub mod word;
 use std::collections::{HashMap, HashSet};
 
-use log::{debug, error};
-use models::{Connection, ConnectionGraph, CycleError, Expr, Node, SignalMap, Wire};
+use aoc_common::{Answer, Input, Solution};
+use log::debug;
+use models::{CircuitError, Connection, ConnectionGraph, Expr, Node, SignalMap, Wire};
+use word::Word;
 
-pub fn run(lines: impl Iterator<Item = String>) -> Option<SignalMap> {
-    signal_map(lines.map(|line| {
-        let parts: Vec<&str> = line.splitn(2, '#').map(|l| l.trim()).collect();
+pub use circuit::Circuit;
+pub use word::Width;
 
-        let connection = parts[0]
-            .parse::<Connection>()
-            .unwrap_or_else(|_| panic!("Error parsing line: {}", line));
+/// 16-bit signals on every wire, like the puzzle's. Later connections to a wire replace earlier
+/// ones.
+///
+/// # Example
+/// ```rust
+/// use circuits::{models::CircuitError, run};
+///
+/// let lines = ["x AND y -> z", "1 -> x"].map(String::from);
+/// assert_eq!(
+///     run(lines.into_iter()),
+///     Err(CircuitError::UndefinedWire { name: "y".to_string(), referenced_by: vec!["z".to_string()] })
+/// );
+/// ```
+pub fn run(lines: impl Iterator<Item = String>) -> Result<SignalMap, CircuitError> {
+    signal_map(parse_connections(lines)?.into_iter())
+}
+
+/// Same as [`run`], without letting more than one connection drive the same wire.
+pub fn run_strict(lines: impl Iterator<Item = String>) -> Result<SignalMap, CircuitError> {
+    signal_map(parse_unique_connections(lines)?.into_iter())
+}
+
+/// Same as [`parse_connections`], failing on wires driven by more than one connection.
+pub fn parse_unique_connections<W: Word>(
+    lines: impl Iterator<Item = String>,
+) -> Result<Vec<Connection<W>>, CircuitError> {
+    let connections = parse_numbered(lines)?;
+    let mut drivers: HashMap<&Wire, Vec<usize>> = HashMap::new();
+    for (line, connection) in &connections {
+        drivers.entry(&connection.target).or_default().push(*line);
+    }
+    let duplicate = connections
+        .iter()
+        .find(|(_, connection)| drivers[&connection.target].len() > 1);
+    if let Some((_, connection)) = duplicate {
+        return Err(CircuitError::DuplicateDriver {
+            wire: connection.target.clone(),
+            lines: drivers[&connection.target].clone(),
+        });
+    }
+    Ok(connections
+        .into_iter()
+        .map(|(_, connection)| connection)
+        .collect())
+}
+
+/// Parses one connection per line, skipping blank lines and `--` comments and ignoring
+/// anything after a `#`.
+pub fn parse_connections<W: Word>(
+    lines: impl Iterator<Item = String>,
+) -> Result<Vec<Connection<W>>, CircuitError> {
+    Ok(parse_numbered(lines)?
+        .into_iter()
+        .map(|(_, connection)| connection)
+        .collect())
+}
 
-        debug!("Parsed connection: {:?}", connection);
+/// Connections along with the 1-based number of the line they are on.
+fn parse_numbered<W: Word>(
+    lines: impl Iterator<Item = String>,
+) -> Result<Vec<(usize, Connection<W>)>, CircuitError> {
+    let mut connections = vec![];
+    for (i, line) in lines.enumerate() {
+        let code = line.split('#').next().unwrap_or_default();
+        let trimmed = code.trim();
+        if trimmed.is_empty() || trimmed.starts_with("--") {
+            continue;
+        }
+        let connection =
+            parsers::parse_connection(trimmed).map_err(|e| CircuitError::ParseError {
+                line: i + 1,
+                column: column(&line, trimmed, e),
+            })?;
+        debug!("Parsed connection: {:?}", connection.1);
+        connections.push((i + 1, connection.1));
+    }
+    Ok(connections)
+}
 
-        connection
-    }))
+/// 1-based position in `line` of the first character nom couldn't make sense of in `code`.
+fn column(line: &str, code: &str, error: nom::Err<nom::error::Error<&str>>) -> usize {
+    let offset = line.len() - line.trim_start().len();
+    let remaining = match error {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e.input.trim_start(),
+        nom::Err::Incomplete(_) => "",
+    };
+    offset + code.len() - remaining.len() + 1
 }
 
-pub fn signal_map(connections: impl Iterator<Item = Connection>) -> Option<SignalMap> {
+pub fn signal_map<W: Word>(
+    connections: impl Iterator<Item = Connection<W>>,
+) -> Result<SignalMap<W>, CircuitError> {
     let graph = from_connections(connections);
+    check_wires(&graph)?;
     let ts = topological_sort(&graph)?;
     debug!(
         "Found a following topological sorting for the connection graph: {:?}",
@@ -34,10 +121,10 @@ pub fn signal_map(connections: impl Iterator<Item = Connection>) -> Option<Signa
         output.insert(wire, value);
     }
 
-    Some(output)
+    Ok(output)
 }
 
-fn from_connections(connections: impl Iterator<Item = Connection>) -> ConnectionGraph {
+pub fn from_connections<W>(connections: impl Iterator<Item = Connection<W>>) -> ConnectionGraph<W> {
     let mut graph = HashMap::new();
     for connection in connections {
         let target = connection.target;
@@ -53,7 +140,28 @@ fn from_connections(connections: impl Iterator<Item = Connection>) -> Connection
     graph
 }
 
-fn resolve_dependencies(expr: &Expr) -> HashSet<Wire> {
+/// Makes sure every wire that is read from is driven by something.
+fn check_wires<W>(graph: &ConnectionGraph<W>) -> Result<(), CircuitError> {
+    let mut undefined: Vec<(&Wire, &Wire)> = graph
+        .iter()
+        .flat_map(|(wire, node)| node.dependencies.iter().map(move |d| (d, wire)))
+        .filter(|(dependency, _)| !graph.contains_key(*dependency))
+        .collect();
+    undefined.sort();
+    match undefined.first() {
+        None => Ok(()),
+        Some(&(name, _)) => Err(CircuitError::UndefinedWire {
+            name: name.clone(),
+            referenced_by: undefined
+                .iter()
+                .filter(|(n, _)| *n == name)
+                .map(|(_, wire)| (*wire).clone())
+                .collect(),
+        }),
+    }
+}
+
+fn resolve_dependencies<W>(expr: &Expr<W>) -> HashSet<Wire> {
     match expr {
         Expr::Symbol(s) => HashSet::from([s.clone()]),
         Expr::Value(_) => HashSet::new(),
@@ -61,7 +169,14 @@ fn resolve_dependencies(expr: &Expr) -> HashSet<Wire> {
         Expr::And(left, right)
         | Expr::Or(left, right)
         | Expr::LShift(left, right)
-        | Expr::RShift(left, right) => {
+        | Expr::RShift(left, right)
+        | Expr::Xor(left, right)
+        | Expr::Nand(left, right)
+        | Expr::Add(left, right)
+        | Expr::Sub(left, right)
+        | Expr::Mul(left, right)
+        | Expr::Div(left, right)
+        | Expr::Mod(left, right) => {
             let l = resolve_dependencies(left);
             let r = resolve_dependencies(right);
             l.union(&r).cloned().collect()
@@ -69,42 +184,47 @@ fn resolve_dependencies(expr: &Expr) -> HashSet<Wire> {
     }
 }
 
-fn topological_sort(graph: &ConnectionGraph) -> Option<Vec<Wire>> {
-    let mut unmarked: HashSet<_> = graph.keys().collect();
-    let mut temp: HashSet<&String> = HashSet::new();
+/// Wires in an order where each one comes after the ones it depends on. Wires are visited in
+/// alphabetical order so that the same cycle is reported every time.
+fn topological_sort<W>(graph: &ConnectionGraph<W>) -> Result<Vec<Wire>, CircuitError> {
+    let mut unmarked: Vec<_> = graph.keys().collect();
+    unmarked.sort();
+    let mut temp: Vec<&String> = Vec::new();
     let mut perm: HashSet<&String> = HashSet::new();
     let mut path: Vec<String> = Vec::new();
 
-    for node in unmarked.drain() {
-        if dfs(graph, node, &mut temp, &mut perm, &mut path).is_err() {
-            error!("Detected cycle in dependency graph. Circuit is not realizable.");
-            return None;
-        }
+    for node in unmarked {
+        dfs(graph, node, &mut temp, &mut perm, &mut path)?;
     }
 
-    Some(path)
+    Ok(path)
 }
 
-fn dfs<'a>(
-    graph: &'a ConnectionGraph,
+/// `temp` holds the wires on the way to `node`, in order.
+fn dfs<'a, W>(
+    graph: &'a ConnectionGraph<W>,
     node: &'a String,
-    temp: &mut HashSet<&'a String>,
+    temp: &mut Vec<&'a String>,
     perm: &mut HashSet<&'a String>,
     path: &mut Vec<String>,
-) -> Result<(), CycleError> {
+) -> Result<(), CircuitError> {
     if perm.contains(node) {
         return Ok(());
     }
 
-    if temp.contains(node) {
-        return Err(CycleError);
+    if let Some(start) = temp.iter().position(|&wire| wire == node) {
+        let mut cycle: Vec<Wire> = temp[start..].iter().map(|&wire| wire.clone()).collect();
+        cycle.push(node.clone());
+        return Err(CircuitError::Cycle { path: cycle });
     }
 
-    temp.insert(node);
-    let dependencies = &graph.get(node).unwrap().dependencies;
-    for dependency in dependencies.iter() {
+    temp.push(node);
+    let mut dependencies: Vec<_> = graph[node].dependencies.iter().collect();
+    dependencies.sort();
+    for dependency in dependencies {
         dfs(graph, dependency, temp, perm, path)?;
     }
+    temp.pop();
     perm.insert(node);
 
     path.push(node.clone());
@@ -112,7 +232,7 @@ fn dfs<'a>(
     Ok(())
 }
 
-fn evaluate(wire_map: &SignalMap, expr: &Expr) -> u16 {
+fn evaluate<W: Word>(wire_map: &SignalMap<W>, expr: &Expr<W>) -> W {
     match expr {
         Expr::Value(v) => *v,
         Expr::Not(v) => {
@@ -132,12 +252,47 @@ fn evaluate(wire_map: &SignalMap, expr: &Expr) -> u16 {
         Expr::LShift(left, right) => {
             let a = evaluate(wire_map, left);
             let b = evaluate(wire_map, right);
-            a << b
+            a.shift_left(b)
         }
         Expr::RShift(left, right) => {
             let a = evaluate(wire_map, left);
             let b = evaluate(wire_map, right);
-            a >> b
+            a.shift_right(b)
+        }
+        Expr::Xor(left, right) => {
+            let a = evaluate(wire_map, left);
+            let b = evaluate(wire_map, right);
+            a ^ b
+        }
+        Expr::Nand(left, right) => {
+            let a = evaluate(wire_map, left);
+            let b = evaluate(wire_map, right);
+            !(a & b)
+        }
+        Expr::Add(left, right) => {
+            let a = evaluate(wire_map, left);
+            let b = evaluate(wire_map, right);
+            a.wrapping_add(b)
+        }
+        Expr::Sub(left, right) => {
+            let a = evaluate(wire_map, left);
+            let b = evaluate(wire_map, right);
+            a.wrapping_sub(b)
+        }
+        Expr::Mul(left, right) => {
+            let a = evaluate(wire_map, left);
+            let b = evaluate(wire_map, right);
+            a.wrapping_mul(b)
+        }
+        Expr::Div(left, right) => {
+            let a = evaluate(wire_map, left);
+            let b = evaluate(wire_map, right);
+            a.div_or_zero(b)
+        }
+        Expr::Mod(left, right) => {
+            let a = evaluate(wire_map, left);
+            let b = evaluate(wire_map, right);
+            a.rem_or_zero(b)
         }
         Expr::Symbol(s) => {
             let value = wire_map.get(s);
@@ -149,6 +304,36 @@ fn evaluate(wire_map: &SignalMap, expr: &Expr) -> u16 {
     }
 }
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        let lines = input.read_lines()?;
+        Ok(signal_on(lines, "a")?.to_string())
+    }
+
+    /// Feeds the signal on `a` back into `b` and measures `a` again, reusing the signals that
+    /// don't depend on `b`.
+    fn part_two(input: &Input) -> Answer {
+        let lines = input.read_lines()?;
+        let mut circuit = Circuit::<u16>::new(parse_connections(lines.into_iter())?);
+        let a = circuit.evaluate("a")?;
+        let mut circuit = circuit.with_override("b", a);
+        Ok(circuit.evaluate("a")?.to_string())
+    }
+}
+
+fn signal_on(lines: Vec<String>, wire: &str) -> Result<u16, CircuitError> {
+    let signal_map = run(lines.into_iter())?;
+    signal_map
+        .get(wire)
+        .copied()
+        .ok_or_else(|| CircuitError::UndefinedWire {
+            name: wire.to_string(),
+            referenced_by: vec![],
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,9 +380,92 @@ mod tests {
         assert_eq!(wire_map.unwrap(), expected);
     }
 
+    #[test]
+    fn test_nested_gates() {
+        let connections = [
+            "12 -> x",
+            "10 -> y",
+            "(NOT x) AND y -> a",
+            "NOT (x AND y) -> b",
+            "x XOR y -> c",
+            "x NAND y -> d",
+            "x ADD y MUL 2 -> e",
+            "y SUB x -> f",
+            "x DIV (y SUB 10) -> g",
+            "x MOD 5 LSHIFT 1 -> h",
+        ];
+        let wire_map = run(connections.into_iter().map(str::to_string)).unwrap();
+        let signal = |wire: &str| wire_map[wire];
+        assert_eq!(signal("a"), 2);
+        assert_eq!(signal("b"), !8);
+        assert_eq!(signal("c"), 6);
+        assert_eq!(signal("d"), !8);
+        assert_eq!(signal("e"), 32);
+        assert_eq!(signal("f"), 65534);
+        assert_eq!(signal("g"), 0);
+        assert_eq!(signal("h"), 4);
+    }
+
     #[test]
     fn test_dag() {
         let result = run(vec!["a -> a".to_string()].into_iter());
-        assert!(result.is_none());
+        assert_eq!(
+            result,
+            Err(CircuitError::Cycle {
+                path: vec!["a".to_string(), "a".to_string()]
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let run = |lines: &[&str]| run(lines.iter().map(|s| s.to_string()));
+        let wires = |wires: &[&str]| wires.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            run(&["1 -> x", "x AND c -> b", "b OR x -> c", "c -> a"]),
+            Err(CircuitError::Cycle {
+                path: wires(&["c", "b", "c"])
+            })
+        );
+        assert_eq!(
+            run(&["q -> b", "b AND q -> a", "p -> c"]),
+            Err(CircuitError::UndefinedWire {
+                name: "p".to_string(),
+                referenced_by: wires(&["c"])
+            })
+        );
+        assert_eq!(
+            run(&["q -> b", "b AND q -> a"]),
+            Err(CircuitError::UndefinedWire {
+                name: "q".to_string(),
+                referenced_by: wires(&["a", "b"])
+            })
+        );
+        assert_eq!(
+            run(&["", "-- a comment", "1 -> x", "  x ANDD 2 -> y # bad gate"]),
+            Err(CircuitError::ParseError { line: 4, column: 5 })
+        );
+        assert_eq!(
+            run(&["1 -> x", "x AND -> y"]),
+            Err(CircuitError::ParseError { line: 2, column: 3 })
+        );
+        assert_eq!(
+            run(&["1 -> 2"]),
+            Err(CircuitError::ParseError { line: 1, column: 6 })
+        );
+    }
+
+    #[test]
+    fn test_strict() {
+        let lines = ["1 -> x", "", "2 -> y", "3 -> x"].map(String::from);
+        assert_eq!(run(lines.clone().into_iter()).unwrap()["x"], 3);
+        assert_eq!(
+            run_strict(lines.into_iter()),
+            Err(CircuitError::DuplicateDriver {
+                wire: "x".to_string(),
+                lines: vec![1, 4]
+            })
+        );
     }
 }
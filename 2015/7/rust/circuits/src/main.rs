@@ -1,19 +1,107 @@
-use circuits::run;
+mod args;
 
-mod io;
+use std::error::Error;
+use std::fmt;
 
-use io::Output;
+use aoc_common::{Input, Source};
+use args::Args;
+use circuits::models::{Connection, Expr, SignalMap};
+use circuits::word::Word;
+use circuits::{dot, from_connections, generate, parse_connections, parse_unique_connections};
+use circuits::{Circuit, Width};
+use clap::Parser;
+use rand::distributions::{Distribution, Standard};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[derive(Debug)]
+enum Output<W> {
+    SingleWire(W),
+    AllWires(SignalMap<W>),
+}
+
+/// The signal of a single wire on its own, or one `signal -> wire` line per wire, sorted by
+/// wire, which reads back as a circuit.
+impl<W: Word> fmt::Display for Output<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Output::SingleWire(signal) => write!(f, "{}", signal),
+            Output::AllWires(signals) => {
+                let mut wires: Vec<_> = signals.iter().collect();
+                wires.sort_by_key(|&(wire, _)| wire);
+                let lines: Vec<String> = wires
+                    .into_iter()
+                    .map(|(wire, signal)| format!("{} -> {}", signal, wire))
+                    .collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+        }
+    }
+}
 
 fn main() {
     env_logger::init();
+    let args = Args::parse();
+    let result = match args.width {
+        Width::U8 => simulate::<u8>(args),
+        Width::U16 => simulate::<u16>(args),
+        Width::U32 => simulate::<u32>(args),
+        Width::U64 => simulate::<u64>(args),
+        Width::I8 => simulate::<i8>(args),
+        Width::I16 => simulate::<i16>(args),
+        Width::I32 => simulate::<i32>(args),
+        Width::I64 => simulate::<i64>(args),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn simulate<W: Word>(args: Args) -> Result<(), Box<dyn Error>>
+where
+    Standard: Distribution<W>,
+{
+    if let Some(wires) = args.generate {
+        let mut rng = match args.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        for connection in generate::netlist::<W>(wires, &mut rng) {
+            println!("{}", connection);
+        }
+        return Ok(());
+    }
+
+    // every line, comments included, so that errors point at the right line
+    let lines = Input::new(Source::from_arg(args.input)).read_lines()?;
+    let mut connections: Vec<Connection<W>> = match args.strict {
+        true => parse_unique_connections(lines.into_iter())?,
+        false => parse_connections(lines.into_iter())?,
+    };
+    for (wire, value) in args.overrides {
+        let value = value
+            .parse()
+            .map_err(|_| format!("invalid signal in override {}={}", wire, value))?;
+        connections.push(Connection {
+            source: Expr::Value(value),
+            target: wire,
+        });
+    }
 
-    let signal_map = run(io::lines());
-    let wire = io::wire();
+    if args.dot {
+        let graph = from_connections(connections.clone().into_iter());
+        let signals = Circuit::new(connections).signals().ok();
+        print!("{}", dot::to_dot(&graph, signals.as_ref()));
+        return Ok(());
+    }
 
-    let output = signal_map.map(|wm| match wire {
-        Ok(w) => wm.get(&w).map_or(Output::Error, |v| Output::SingleWire(*v)),
-        Err(_) => Output::AllWires(wm),
-    });
+    // only the wires the requested one depends on are evaluated
+    let mut circuit = Circuit::new(connections);
+    let output = match args.wire {
+        Some(wire) => Output::SingleWire(circuit.evaluate(&wire)?),
+        None => Output::AllWires(circuit.signals()?),
+    };
 
-    println!("{:?}", output);
+    println!("{}", output);
+    Ok(())
 }
@@ -1,27 +1,157 @@
 use crate::parsers::{parse_connection, parse_expr};
+use crate::word::Word;
 use nom::combinator::all_consuming;
 use std::{
     collections::{HashMap, HashSet},
+    fmt,
     str::FromStr,
 };
 
 pub type Wire = String;
-pub type SignalMap = HashMap<Wire, u16>;
-pub type ConnectionGraph = HashMap<Wire, Node>;
-pub struct CycleError;
+pub type SignalMap<W = u16> = HashMap<Wire, W>;
+pub type ConnectionGraph<W = u16> = HashMap<Wire, Node<W>>;
 
-#[derive(Debug, PartialEq)]
-pub enum Expr {
+/// Why a circuit can't be built or evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// Wires that depend on each other, starting and ending with the same wire.
+    Cycle { path: Vec<Wire> },
+    /// A wire that nothing drives, and the wires that read from it.
+    UndefinedWire {
+        name: Wire,
+        referenced_by: Vec<Wire>,
+    },
+    /// A wire driven by connections on more than one line.
+    DuplicateDriver { wire: Wire, lines: Vec<usize> },
+    /// A line that isn't a connection, with the 1-based position where it stops being one.
+    ParseError { line: usize, column: usize },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CircuitError::Cycle { path } => write!(f, "cycle: {}", path.join(" -> ")),
+            CircuitError::UndefinedWire {
+                name,
+                referenced_by,
+            } if referenced_by.is_empty() => write!(f, "wire {} is not driven", name),
+            CircuitError::UndefinedWire {
+                name,
+                referenced_by,
+            } => write!(
+                f,
+                "wire {} is not driven, but {} reads it",
+                name,
+                referenced_by.join(", ")
+            ),
+            CircuitError::DuplicateDriver { wire, lines } => {
+                let lines: Vec<String> = lines.iter().map(usize::to_string).collect();
+                write!(f, "wire {} is driven on lines {}", wire, lines.join(", "))
+            }
+            CircuitError::ParseError { line, column } => {
+                write!(f, "line {}, column {}: not a connection", line, column)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr<W = u16> {
     Symbol(Wire),
-    Value(u16),
-    And(Box<Expr>, Box<Expr>),
-    Or(Box<Expr>, Box<Expr>),
-    LShift(Box<Expr>, Box<Expr>),
-    RShift(Box<Expr>, Box<Expr>),
-    Not(Box<Expr>),
+    Value(W),
+    And(Box<Expr<W>>, Box<Expr<W>>),
+    Or(Box<Expr<W>>, Box<Expr<W>>),
+    /// Shifting by the width of the word or more shifts every bit out.
+    LShift(Box<Expr<W>>, Box<Expr<W>>),
+    RShift(Box<Expr<W>>, Box<Expr<W>>),
+    Not(Box<Expr<W>>),
+    Xor(Box<Expr<W>>, Box<Expr<W>>),
+    Nand(Box<Expr<W>>, Box<Expr<W>>),
+    /// Arithmetic gates wrap around on overflow.
+    Add(Box<Expr<W>>, Box<Expr<W>>),
+    Sub(Box<Expr<W>>, Box<Expr<W>>),
+    Mul(Box<Expr<W>>, Box<Expr<W>>),
+    /// Dividing by zero gives zero, as does [`Expr::Mod`].
+    Div(Box<Expr<W>>, Box<Expr<W>>),
+    Mod(Box<Expr<W>>, Box<Expr<W>>),
 }
 
-impl FromStr for Expr {
+impl<W> Expr<W> {
+    /// Name of the gate, `None` for wires and values.
+    pub fn gate(&self) -> Option<&'static str> {
+        Some(match self {
+            Expr::Symbol(_) | Expr::Value(_) => return None,
+            Expr::And(..) => "AND",
+            Expr::Or(..) => "OR",
+            Expr::LShift(..) => "LSHIFT",
+            Expr::RShift(..) => "RSHIFT",
+            Expr::Not(_) => "NOT",
+            Expr::Xor(..) => "XOR",
+            Expr::Nand(..) => "NAND",
+            Expr::Add(..) => "ADD",
+            Expr::Sub(..) => "SUB",
+            Expr::Mul(..) => "MUL",
+            Expr::Div(..) => "DIV",
+            Expr::Mod(..) => "MOD",
+        })
+    }
+
+    /// The expressions feeding into the gate, none for wires and values.
+    pub fn inputs(&self) -> Vec<&Expr<W>> {
+        match self {
+            Expr::Symbol(_) | Expr::Value(_) => vec![],
+            Expr::Not(expr) => vec![expr],
+            Expr::And(left, right)
+            | Expr::Or(left, right)
+            | Expr::LShift(left, right)
+            | Expr::RShift(left, right)
+            | Expr::Xor(left, right)
+            | Expr::Nand(left, right)
+            | Expr::Add(left, right)
+            | Expr::Sub(left, right)
+            | Expr::Mul(left, right)
+            | Expr::Div(left, right)
+            | Expr::Mod(left, right) => vec![left, right],
+        }
+    }
+}
+
+/// Writes the expression back the way it is parsed, with gates feeding into other gates in
+/// parentheses.
+///
+/// # Example
+/// ```rust
+/// use circuits::models::Expr;
+///
+/// let expr: Expr = "NOT x AND (y OR 3)".parse().unwrap();
+/// assert_eq!(expr.to_string(), "(NOT x) AND (y OR 3)");
+/// assert_eq!(expr.to_string().parse::<Expr>(), Ok(expr));
+/// ```
+impl<W: Word> fmt::Display for Expr<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operand = |expr: &Expr<W>| match expr.gate() {
+            None => expr.to_string(),
+            Some(_) => format!("({})", expr),
+        };
+        match (self, &self.inputs()[..]) {
+            (Expr::Symbol(wire), _) => write!(f, "{}", wire),
+            (Expr::Value(value), _) => write!(f, "{}", value),
+            (_, [expr]) => write!(f, "NOT {}", operand(expr)),
+            (_, [left, right]) => write!(
+                f,
+                "{} {} {}",
+                operand(left),
+                self.gate().unwrap_or_default(),
+                operand(right)
+            ),
+            _ => unreachable!("gates take one or two inputs"),
+        }
+    }
+}
+
+impl<W: Word> FromStr for Expr<W> {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -30,13 +160,19 @@ impl FromStr for Expr {
     }
 }
 
-#[derive(Debug, PartialEq)]
-pub struct Connection {
-    pub source: Expr,
+#[derive(Debug, Clone, PartialEq)]
+pub struct Connection<W = u16> {
+    pub source: Expr<W>,
     pub target: Wire,
 }
 
-impl FromStr for Connection {
+impl<W: Word> fmt::Display for Connection<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} -> {}", self.source, self.target)
+    }
+}
+
+impl<W: Word> FromStr for Connection<W> {
     type Err = ();
 
     /// Parses a string into a Connection
@@ -70,7 +206,7 @@ impl FromStr for Connection {
 }
 
 #[derive(Debug)]
-pub struct Node {
-    pub expr: Expr,
+pub struct Node<W = u16> {
+    pub expr: Expr<W>,
     pub dependencies: HashSet<Wire>,
 }
@@ -1,17 +1,18 @@
 use nom::branch::alt;
-use nom::bytes::complete::{tag, take_till1, take_until};
-use nom::character::complete::{alpha1, digit1, multispace0, multispace1, space0};
-use nom::combinator::{all_consuming, map_res, recognize, rest};
-use nom::sequence::{delimited, preceded, separated_pair, tuple};
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, alphanumeric1, char, digit1, multispace0};
+use nom::combinator::{all_consuming, map_res, not, opt, peek, recognize, verify};
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated, tuple};
 use nom::IResult;
 
 use crate::models::{Connection, Expr, Wire};
+use crate::word::Word;
 
-pub fn parse_connection(input: &str) -> IResult<&str, Connection> {
+pub fn parse_connection<W: Word>(input: &str) -> IResult<&str, Connection<W>> {
     let (remaining, (expr, wire)) = all_consuming(separated_pair(
         parse_expr,
         tuple((multispace0, tag("->"), multispace0)),
-        alpha1,
+        parse_wire,
     ))(input)?;
     Ok((
         remaining,
@@ -22,68 +23,113 @@ pub fn parse_connection(input: &str) -> IResult<&str, Connection> {
     ))
 }
 
-pub fn parse_expr(input: &str) -> IResult<&str, Expr> {
-    alt((
-        parse_binary_gate("OR", |l, r| Expr::Or(Box::new(l), Box::new(r))),
-        parse_binary_gate("AND", |l, r| Expr::And(Box::new(l), Box::new(r))),
-        parse_binary_gate("LSHIFT", |l, r| Expr::LShift(Box::new(l), Box::new(r))),
-        parse_binary_gate("RSHIFT", |l, r| Expr::RShift(Box::new(l), Box::new(r))),
-        parse_not,
-        parse_value,
-        parse_symbol,
-    ))(input)
+/// Binary gates, from the loosest to the tightest binding. Gates on the same level associate
+/// to the left, so `x SUB y SUB z` is `(x SUB y) SUB z`.
+const PRECEDENCE: [&[&str]; 6] = [
+    &["OR"],
+    &["XOR"],
+    &["AND", "NAND"],
+    &["LSHIFT", "RSHIFT"],
+    &["ADD", "SUB"],
+    &["MUL", "DIV", "MOD"],
+];
+
+/// The binary gate called `name`, one of those in [`PRECEDENCE`].
+fn binary_gate<W>(name: &str, left: Box<Expr<W>>, right: Box<Expr<W>>) -> Expr<W> {
+    match name {
+        "OR" => Expr::Or(left, right),
+        "XOR" => Expr::Xor(left, right),
+        "AND" => Expr::And(left, right),
+        "NAND" => Expr::Nand(left, right),
+        "LSHIFT" => Expr::LShift(left, right),
+        "RSHIFT" => Expr::RShift(left, right),
+        "ADD" => Expr::Add(left, right),
+        "SUB" => Expr::Sub(left, right),
+        "MUL" => Expr::Mul(left, right),
+        "DIV" => Expr::Div(left, right),
+        "MOD" => Expr::Mod(left, right),
+        _ => unreachable!("unknown gate {}", name),
+    }
 }
 
-pub fn take_word(input: &str) -> IResult<&str, &str> {
-    preceded(space0, recognize(take_till1(|c| c == ' ')))(input)
+const KEYWORDS: [&str; 12] = [
+    "NOT", "OR", "XOR", "AND", "NAND", "LSHIFT", "RSHIFT", "ADD", "SUB", "MUL", "DIV", "MOD",
+];
+
+/// Parses an expression, gates binding tighter than the ones in [`PRECEDENCE`] before them,
+/// `NOT` tighter than any of them and parentheses tightest of all.
+pub fn parse_expr<W: Word>(input: &str) -> IResult<&str, Expr<W>> {
+    parse_level(0, input)
 }
 
-fn parse_value(input: &str) -> IResult<&str, Expr> {
-    let (remaining, value) = map_res(digit1, str::parse)(input)?;
-    Ok((remaining, Expr::Value(value)))
+fn parse_level<W: Word>(level: usize, input: &str) -> IResult<&str, Expr<W>> {
+    let Some(gates) = PRECEDENCE.get(level) else {
+        return parse_unary(input);
+    };
+    let (mut input, mut left) = parse_level(level + 1, input)?;
+    loop {
+        let gate = preceded(multispace0, |i| parse_gate(gates, i));
+        let operand = preceded(multispace0, |i| parse_level(level + 1, i));
+        match pair(gate, operand)(input) {
+            Ok((remaining, (name, right))) => {
+                left = binary_gate(name, Box::new(left), Box::new(right));
+                input = remaining;
+            }
+            Err(nom::Err::Error(_)) => return Ok((input, left)),
+            Err(e) => return Err(e),
+        }
+    }
 }
 
-fn parse_symbol(input: &str) -> IResult<&str, Expr> {
-    // TODO: reject protected symbols
-    let (remaining, value) = alpha1(input)?;
-    Ok((remaining, Expr::Symbol(Wire::from(value))))
+fn parse_gate<'a>(gates: &[&'static str], input: &'a str) -> IResult<&'a str, &'static str> {
+    for name in gates {
+        if let Ok((remaining, _)) = keyword(name)(input) {
+            return Ok((remaining, name));
+        }
+    }
+    Err(nom::Err::Error(nom::error::Error::new(
+        input,
+        nom::error::ErrorKind::Tag,
+    )))
 }
 
-fn parse_not(input: &str) -> IResult<&str, Expr> {
-    let (remaining, expr) = preceded(tag("NOT "), parse_expr)(input)?;
-    Ok((remaining, Expr::Not(Box::new(expr))))
+/// `word`, as long as it isn't the beginning of a longer one.
+fn keyword<'a>(word: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    terminated(tag(word), not(peek(alphanumeric1)))
 }
 
-/// TODO: review leaves and nodes. leaves are symbols and values. maximally reduced. others are nodes.
+fn parse_unary<W: Word>(input: &str) -> IResult<&str, Expr<W>> {
+    alt((parse_not, parse_parens, parse_value, parse_symbol))(input)
+}
 
-fn _parse_left_right(marker: &str) -> impl Fn(&str) -> IResult<&str, (&str, &str)> + '_ {
-    move |input: &str| {
-        let (input, left) = take_until(marker)(input)?;
-        let (input, _) = tag(marker)(input)?;
-        let (input, right) = rest(input)?;
-        Ok((input, (left, right)))
-    }
+/// A value that fits in a word, negative ones only fitting in signed words.
+fn parse_value<W: Word>(input: &str) -> IResult<&str, Expr<W>> {
+    let literal = recognize(pair(opt(char('-')), digit1));
+    let (remaining, value) = map_res(literal, str::parse)(input)?;
+    Ok((remaining, Expr::Value(value)))
 }
 
-fn parse_binary_gate<'a, F>(
-    marker: &'a str,
-    binary_gate: F,
-) -> impl Fn(&str) -> IResult<&str, Expr> + 'a
-where
-    F: Fn(Expr, Expr) -> Expr + 'a,
-{
-    move |input| {
-        let (remaining, (left, right)) = separated_pair(
-            take_word,
-            delimited(multispace1, tag(marker), multispace1),
-            take_word,
-        )(input)?;
-
-        let (_, left) = parse_expr(left)?;
-        let (_, right) = parse_expr(right)?;
-
-        Ok((remaining, binary_gate(left, right)))
-    }
+/// A wire name, which can't be one of the gates.
+fn parse_wire(input: &str) -> IResult<&str, &str> {
+    verify(alpha1, |name: &str| !KEYWORDS.contains(&name))(input)
+}
+
+fn parse_symbol<W>(input: &str) -> IResult<&str, Expr<W>> {
+    let (remaining, value) = parse_wire(input)?;
+    Ok((remaining, Expr::Symbol(Wire::from(value))))
+}
+
+fn parse_not<W: Word>(input: &str) -> IResult<&str, Expr<W>> {
+    let (remaining, expr) = preceded(pair(keyword("NOT"), multispace0), parse_unary)(input)?;
+    Ok((remaining, Expr::Not(Box::new(expr))))
+}
+
+fn parse_parens<W: Word>(input: &str) -> IResult<&str, Expr<W>> {
+    delimited(
+        pair(char('('), multispace0),
+        parse_expr,
+        pair(multispace0, char(')')),
+    )(input)
 }
 
 #[cfg(test)]
@@ -140,6 +186,16 @@ mod tests {
         assert_eq!("123".parse::<Expr>().unwrap(), Expr::Value(123));
         assert_eq!("65535".parse::<Expr>().unwrap(), Expr::Value(65535));
         assert!("65536".parse::<Expr>().is_err());
+        assert!("-1".parse::<Expr>().is_err());
+        assert_eq!("-128".parse::<Expr<i8>>(), Ok(Expr::Value(-128)));
+        assert!("128".parse::<Expr<i8>>().is_err());
+        assert_eq!(
+            "x SUB -3 -> y".parse::<Connection<i64>>().map(|c| c.source),
+            Ok(Expr::Sub(
+                Box::new(Expr::Symbol(Wire::from("x"))),
+                Box::new(Expr::Value(-3))
+            ))
+        );
     }
 
     #[test]
@@ -277,22 +333,77 @@ mod tests {
         );
     }
 
-    // #[test]
-    // fn test_parens() {
-    //     assert_eq!(
-    //         "(NOT x) AND y".parse::<Expr>(),
-    //         Ok(Expr::And(
-    //             Box::new(Expr::Not(Box::new(Expr::Symbol(Wire::from("x"))))),
-    //             Box::new(Expr::Symbol(Wire::from("y")))
-    //         ))
-    //     );
-
-    //     assert_eq!(
-    //         "NOT (x AND y)".parse::<Expr>(),
-    //         Ok(Expr::Not(Box::new(Expr::And(
-    //             Box::new(Expr::Symbol(Wire::from("x"))),
-    //             Box::new(Expr::Symbol(Wire::from("y")))
-    //         ))))
-    //     );
-    // }
+    #[test]
+    fn test_parens() {
+        assert_eq!(
+            "(NOT x) AND y".parse::<Expr>(),
+            Ok(Expr::And(
+                Box::new(Expr::Not(Box::new(Expr::Symbol(Wire::from("x"))))),
+                Box::new(Expr::Symbol(Wire::from("y")))
+            ))
+        );
+
+        assert_eq!(
+            "NOT (x AND y)".parse::<Expr>(),
+            Ok(Expr::Not(Box::new(Expr::And(
+                Box::new(Expr::Symbol(Wire::from("x"))),
+                Box::new(Expr::Symbol(Wire::from("y")))
+            ))))
+        );
+
+        assert_eq!(
+            "( ( x ) )".parse::<Expr>(),
+            Ok(Expr::Symbol(Wire::from("x")))
+        );
+        assert!("(x AND y".parse::<Expr>().is_err());
+        assert!("x AND y)".parse::<Expr>().is_err());
+    }
+
+    #[test]
+    fn test_precedence() {
+        let x = || Box::new(Expr::Symbol(Wire::from("x")));
+        let y = || Box::new(Expr::Symbol(Wire::from("y")));
+        let z = || Box::new(Expr::Symbol(Wire::from("z")));
+
+        assert_eq!(
+            "x OR y AND z".parse::<Expr>(),
+            Ok(Expr::Or(x(), Box::new(Expr::And(y(), z()))))
+        );
+        assert_eq!(
+            "x AND y OR z".parse::<Expr>(),
+            Ok(Expr::Or(Box::new(Expr::And(x(), y())), z()))
+        );
+        assert_eq!(
+            "NOT x XOR y".parse::<Expr>(),
+            Ok(Expr::Xor(Box::new(Expr::Not(x())), y()))
+        );
+        assert_eq!(
+            "x SUB y SUB z".parse::<Expr>(),
+            Ok(Expr::Sub(Box::new(Expr::Sub(x(), y())), z()))
+        );
+        assert_eq!(
+            "x LSHIFT y ADD z MUL 2".parse::<Expr>(),
+            Ok(Expr::LShift(
+                x(),
+                Box::new(Expr::Add(
+                    y(),
+                    Box::new(Expr::Mul(z(), Box::new(Expr::Value(2))))
+                ))
+            ))
+        );
+        assert_eq!("x NAND y".parse::<Expr>(), Ok(Expr::Nand(x(), y())));
+        assert_eq!(
+            "x MOD (y DIV z)".parse::<Expr>(),
+            Ok(Expr::Mod(x(), Box::new(Expr::Div(y(), z()))))
+        );
+    }
+
+    #[test]
+    fn test_keywords() {
+        assert!("AND".parse::<Expr>().is_err());
+        assert!("x AND OR".parse::<Expr>().is_err());
+        assert!("x ANDy".parse::<Expr>().is_err());
+        assert!("x -> NOT".parse::<Connection>().is_err());
+        assert_eq!("ORx".parse::<Expr>(), Ok(Expr::Symbol(Wire::from("ORx"))));
+    }
 }
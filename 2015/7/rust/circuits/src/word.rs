@@ -0,0 +1,179 @@
+//! The integers signals are carried on.
+
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+use std::str::FromStr;
+
+use clap::{builder::PossibleValue, ValueEnum};
+
+/// A fixed width integer for signals. Arithmetic wraps around, dividing by zero gives zero and
+/// shifting by the width or more shifts every bit out, so that no signal makes a gate panic.
+pub trait Word:
+    Copy
+    + Default
+    + Eq
+    + Hash
+    + fmt::Debug
+    + fmt::Display
+    + FromStr
+    + Not<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Send
+    + Sync
+    + 'static
+{
+    const BITS: u32;
+
+    /// The lowest bits of `value`.
+    fn truncate(value: u64) -> Self;
+
+    fn wrapping_add(self, other: Self) -> Self;
+
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    fn wrapping_mul(self, other: Self) -> Self;
+
+    fn div_or_zero(self, divisor: Self) -> Self;
+
+    fn rem_or_zero(self, divisor: Self) -> Self;
+
+    /// Shifts zeros in from the right.
+    fn shift_left(self, amount: Self) -> Self;
+
+    /// Shifts zeros in from the left, or copies of the sign bit for signed words.
+    fn shift_right(self, amount: Self) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {$(
+        impl Word for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            fn truncate(value: u64) -> Self {
+                value as $t
+            }
+
+            fn wrapping_add(self, other: Self) -> Self {
+                <$t>::wrapping_add(self, other)
+            }
+
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$t>::wrapping_sub(self, other)
+            }
+
+            fn wrapping_mul(self, other: Self) -> Self {
+                <$t>::wrapping_mul(self, other)
+            }
+
+            fn div_or_zero(self, divisor: Self) -> Self {
+                match divisor {
+                    0 => 0,
+                    _ => self.wrapping_div(divisor),
+                }
+            }
+
+            fn rem_or_zero(self, divisor: Self) -> Self {
+                match divisor {
+                    0 => 0,
+                    _ => self.wrapping_rem(divisor),
+                }
+            }
+
+            // negative amounts are as large as they get once seen as u64
+            fn shift_left(self, amount: Self) -> Self {
+                match amount as u64 {
+                    n if n < Self::BITS as u64 => self << n,
+                    _ => 0,
+                }
+            }
+
+            fn shift_right(self, amount: Self) -> Self {
+                match amount as u64 {
+                    n if n < Self::BITS as u64 => self >> n,
+                    _ => self >> (Self::BITS - 1) >> 1,
+                }
+            }
+        }
+    )*};
+}
+
+impl_word!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// The word types a circuit can be simulated with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Width {
+    U8,
+    #[default]
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+}
+
+impl Width {
+    pub const ALL: [Width; 8] = [
+        Width::U8,
+        Width::U16,
+        Width::U32,
+        Width::U64,
+        Width::I8,
+        Width::I16,
+        Width::I32,
+        Width::I64,
+    ];
+}
+
+impl ValueEnum for Width {
+    fn value_variants<'a>() -> &'a [Self] {
+        &Self::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::U8 => PossibleValue::new("u8").help("8-bit unsigned"),
+            Self::U16 => PossibleValue::new("u16").help("16-bit unsigned, like the puzzle"),
+            Self::U32 => PossibleValue::new("u32").help("32-bit unsigned"),
+            Self::U64 => PossibleValue::new("u64").help("64-bit unsigned"),
+            Self::I8 => PossibleValue::new("i8").help("8-bit two's complement"),
+            Self::I16 => PossibleValue::new("i16").help("16-bit two's complement"),
+            Self::I32 => PossibleValue::new("i32").help("32-bit two's complement"),
+            Self::I64 => PossibleValue::new("i64").help("64-bit two's complement"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shifts() {
+        assert_eq!(1u16.shift_left(15), 0x8000);
+        assert_eq!(1u16.shift_left(16), 0);
+        assert_eq!(0xffffu16.shift_right(16), 0);
+        assert_eq!(0x80u8.shift_right(7), 1);
+        assert_eq!(u64::MAX.shift_left(200), 0);
+        assert_eq!((-8i8).shift_right(2), -2);
+        assert_eq!((-8i8).shift_right(8), -1);
+        assert_eq!(8i8.shift_right(100), 0);
+        assert_eq!(1i32.shift_left(-1), 0);
+        assert_eq!(1i32.shift_left(31), i32::MIN);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(7u8.div_or_zero(0), 0);
+        assert_eq!(7u8.rem_or_zero(0), 0);
+        assert_eq!(i16::MIN.div_or_zero(-1), i16::MIN);
+        assert_eq!(i16::MIN.rem_or_zero(-1), 0);
+        assert_eq!(Word::wrapping_sub(0u32, 1), u32::MAX);
+        assert_eq!(u8::truncate(0x1234), 0x34);
+        assert_eq!(i8::truncate(0xff), -1);
+    }
+}
@@ -1,25 +1,12 @@
-use std::fs::File;
-use std::io::{self, BufRead};
+use aoc_common::Input;
 
-pub fn lines() -> Box<dyn Iterator<Item = String>> {
-    match std::env::args().nth(1) {
-        None => Box::new(
-            io::stdin()
-                .lock()
-                .lines()
-                .filter_map(Result::ok)
-                .filter(|line| !line.starts_with("--")),
-        ),
-        Some(path) => {
-            let file = File::open(path).expect("error reading file");
-            Box::new(io::BufReader::new(file).lines().filter_map(Result::ok))
-        }
-    }
+pub fn lines() -> std::io::Result<Vec<String>> {
+    Input::from_args().comments("--").read_lines()
 }
 
 pub enum Part {
-    ONE,
-    TWO,
+    One,
+    Two,
 }
 
 pub fn part() -> Part {
@@ -29,8 +16,8 @@ pub fn part() -> Part {
         .expect("invalid PART");
 
     match part {
-        1 => Part::ONE,
-        2 => Part::TWO,
+        1 => Part::One,
+        2 => Part::Two,
         _ => panic!("invalid PART"),
     }
 }
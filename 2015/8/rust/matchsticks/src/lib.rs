@@ -1,30 +1,114 @@
-use std::collections::HashSet;
+use std::fmt::Write;
 
+use aoc_common::{Answer, Input, Solution};
+
+pub mod models;
 mod parsers;
 
-use parsers::parse_count;
+use models::DecodeError;
 
-pub fn counts(line: &str) -> (usize, usize) {
-    let (_, count) = parse_count(line).expect("Invalid input");
-    (line.len(), count)
+/// Bytes a double quoted string literal stands for. Besides `\\`, `\"` and `\xHH`, which
+/// can be any byte, the escapes are `\'`, `\n`, `\r`, `\t` and `\0`.
+///
+/// # Example
+/// ```rust
+/// use matchsticks::decode;
+///
+/// assert_eq!(decode(r#""a\"b\x27\xff""#), Ok(b"a\"b'\xff".to_vec()));
+/// let error = decode(r#""a\x2g""#).unwrap_err();
+/// assert_eq!(error.to_string(), "column 5: expected two hex digits");
+/// ```
+pub fn decode(literal: &str) -> Result<Vec<u8>, DecodeError> {
+    match parsers::parse_literal(literal) {
+        Ok((_, bytes)) => Ok(bytes),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(DecodeError::new(literal, e)),
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers only"),
+    }
+}
+
+/// A double quoted string literal standing for `bytes`, which [`decode`] turns back into them.
+/// Printable ASCII stays as it is, other bytes are escaped.
+///
+/// # Example
+/// ```rust
+/// use matchsticks::{decode, encode};
+///
+/// assert_eq!(encode(b"say \"hi\"\n\x80"), r#""say \"hi\"\n\x80""#);
+/// assert_eq!(decode(&encode(b"\0\\")), Ok(b"\0\\".to_vec()));
+/// ```
+pub fn encode(bytes: &[u8]) -> String {
+    let mut literal = String::from('"');
+    for &byte in bytes {
+        match byte {
+            b'"' => literal.push_str("\\\""),
+            b'\\' => literal.push_str("\\\\"),
+            b'\n' => literal.push_str("\\n"),
+            b'\r' => literal.push_str("\\r"),
+            b'\t' => literal.push_str("\\t"),
+            0 => literal.push_str("\\0"),
+            b' '..=b'~' => literal.push(byte as char),
+            _ => {
+                let _ = write!(literal, "\\x{:02x}", byte);
+            }
+        }
+    }
+    literal.push('"');
+    literal
 }
 
+/// Length of the literal as written, and of what it stands for in memory.
+pub fn counts(line: &str) -> Result<(usize, usize), DecodeError> {
+    Ok((line.len(), decode(line)?.len()))
+}
+
+/// Length of `input` once written as a literal, and of `input` itself.
 pub fn encode_counts(input: &str) -> (usize, usize) {
-    let to_escape = HashSet::from(['"', '\\']);
-    let extra = input.chars().filter(|c| to_escape.contains(c)).count();
-    return (input.len() + 2 + extra, input.len());
+    (encode(input.as_bytes()).len(), input.len())
+}
+
+/// Sums up the difference between the left and right hand counts of every line.
+pub fn overhead<S: AsRef<str>>(
+    lines: &[S],
+    mapper: fn(&str) -> Result<(usize, usize), DecodeError>,
+) -> Result<usize, DecodeError> {
+    lines.iter().try_fold(0, |acc, line| {
+        let (l, r) = mapper(line.as_ref())?;
+        Ok(acc + l - r)
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        let lines = input.clone().comments("--").read_lines()?;
+        Ok(overhead(&lines, counts)?.to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let lines = input.clone().comments("--").read_lines()?;
+        Ok(overhead(&lines, |line| Ok(encode_counts(line)))?.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_counts() {
-        assert_eq!(counts("\"\""), (2, 0));
-        assert_eq!(counts("\"abc\""), (5, 3));
-        assert_eq!(counts("\"aaa\\\"aaa\""), (10, 7));
-        assert_eq!(counts("\"\\x27\""), (6, 1));
+        assert_eq!(counts("\"\""), Ok((2, 0)));
+        assert_eq!(counts("\"abc\""), Ok((5, 3)));
+        assert_eq!(counts("\"aaa\\\"aaa\""), Ok((10, 7)));
+        assert_eq!(counts("\"\\x27\""), Ok((6, 1)));
+        assert_eq!(
+            counts("\"a\\z\""),
+            Err(DecodeError {
+                position: 3,
+                expected: "one of \\ \" ' n r t 0 x"
+            })
+        );
     }
 
     #[test]
@@ -34,4 +118,17 @@ mod tests {
         assert_eq!(encode_counts("\"aaa\\\"aaa\""), (16, 10));
         assert_eq!(encode_counts("\"\\x27\""), (11, 6));
     }
+
+    proptest! {
+        #[test]
+        fn round_trip(bytes in proptest::collection::vec(any::<u8>(), 0..64)) {
+            prop_assert_eq!(decode(&encode(&bytes)), Ok(bytes));
+        }
+
+        #[test]
+        fn reencodes(line in r#""([a-z '~]|\\\\|\\"|\\x[0-9a-f]{2}|\\[nrt0'])*""#) {
+            let bytes = decode(&line).unwrap();
+            prop_assert_eq!(decode(&encode(&bytes)), Ok(bytes));
+        }
+    }
 }
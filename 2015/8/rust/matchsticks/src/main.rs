@@ -2,18 +2,21 @@ mod io;
 
 use io::lines;
 
-use matchsticks::{counts, encode_counts};
+use matchsticks::{counts, encode_counts, overhead};
 
-fn main() {
-    let mapper = match io::part() {
-        io::Part::ONE => counts,
-        io::Part::TWO => encode_counts,
+fn main() -> std::io::Result<()> {
+    let lines = lines()?;
+    let overhead = match io::part() {
+        io::Part::One => overhead(&lines, counts),
+        io::Part::Two => overhead(&lines, |line| Ok(encode_counts(line))),
     };
 
-    println!(
-        "{}",
-        lines()
-            .map(|line| mapper(&line))
-            .fold(0, |acc, (l, r)| acc + l - r)
-    );
+    match overhead {
+        Ok(overhead) => println!("{}", overhead),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+    Ok(())
 }
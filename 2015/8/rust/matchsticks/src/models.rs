@@ -0,0 +1,33 @@
+use std::fmt;
+
+use aoc_common::parsing::Expected;
+
+/// Why a string literal can't be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    /// Byte offset, in the literal, of where it stops making sense.
+    pub position: usize,
+    pub expected: &'static str,
+}
+
+impl DecodeError {
+    pub(crate) fn new(literal: &str, expected: Expected) -> Self {
+        DecodeError {
+            position: literal.len() - expected.input.len(),
+            expected: expected.what,
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "column {}: expected {}",
+            self.position + 1,
+            self.expected
+        )
+    }
+}
+
+impl std::error::Error for DecodeError {}
@@ -1,39 +1,89 @@
+use aoc_common::parsing::{expect, Parsed};
 use nom::branch::alt;
-use nom::bytes::complete::{tag, take_while_m_n};
-use nom::character::complete::alphanumeric1;
-use nom::combinator::all_consuming;
-use nom::multi::many0;
-use nom::sequence::preceded;
-use nom::IResult;
-
-pub fn parse_count(input: &str) -> IResult<&str, usize> {
-    let (input, _) = tag("\"")(input)?;
-    let (input, xs) = many0(alt((parse_hex, parse_quote, parse_backslash, parse_seq)))(input)?;
-    let _ = all_consuming(tag("\""))(input)?;
-
-    Ok((input, xs.iter().sum()))
+use nom::bytes::complete::{is_not, take_while_m_n};
+use nom::character::complete::char;
+use nom::combinator::{cut, eof, map, map_res, value};
+use nom::multi::fold_many0;
+use nom::sequence::{delimited, preceded, terminated};
+
+/// Part of a literal: characters standing for themselves, or an escaped byte.
+enum Piece<'a> {
+    Text(&'a str),
+    Byte(u8),
 }
 
-fn is_hex_digit(c: char) -> bool {
-    c.is_ascii_hexdigit()
+/// A double quoted string literal, and nothing after it, as the bytes it stands for.
+pub fn parse_literal(input: &str) -> Parsed<'_, Vec<u8>> {
+    let pieces = fold_many0(parse_piece, Vec::new, |mut bytes, piece| {
+        match piece {
+            Piece::Text(text) => bytes.extend_from_slice(text.as_bytes()),
+            Piece::Byte(byte) => bytes.push(byte),
+        }
+        bytes
+    });
+    terminated(
+        delimited(
+            expect("an opening quote", char('"')),
+            pieces,
+            expect("a closing quote", char('"')),
+        ),
+        expect("the end of the literal", eof),
+    )(input)
 }
 
-fn parse_hex(input: &str) -> IResult<&str, usize> {
-    let (remaining, _) = preceded(tag(r"\x"), take_while_m_n(2, 2, is_hex_digit))(input)?;
-    Ok((remaining, 1))
+fn parse_piece(input: &str) -> Parsed<'_, Piece<'_>> {
+    alt((
+        map(is_not("\\\""), Piece::Text),
+        map(parse_escape, Piece::Byte),
+    ))(input)
 }
 
-fn parse_quote(input: &str) -> IResult<&str, usize> {
-    let (remaining, _) = tag("\\\"")(input)?;
-    Ok((remaining, 1))
+/// A backslash followed by one of `\ " ' n r t 0`, or by `x` and two hex digits.
+fn parse_escape(input: &str) -> Parsed<'_, u8> {
+    let escape = alt((
+        value(b'\\', char('\\')),
+        value(b'"', char('"')),
+        value(b'\'', char('\'')),
+        value(b'\n', char('n')),
+        value(b'\r', char('r')),
+        value(b'\t', char('t')),
+        value(0, char('0')),
+        preceded(char('x'), cut(expect("two hex digits", parse_hex))),
+    ));
+    preceded(char('\\'), cut(expect("one of \\ \" ' n r t 0 x", escape)))(input)
 }
 
-fn parse_backslash(input: &str) -> IResult<&str, usize> {
-    let (remaining, _) = tag(r"\\")(input)?;
-    Ok((remaining, 1))
+fn parse_hex(input: &str) -> Parsed<'_, u8> {
+    let digits = take_while_m_n(2, 2, |c: char| c.is_ascii_hexdigit());
+    map_res(digits, |digits| u8::from_str_radix(digits, 16))(input)
 }
 
-fn parse_seq(input: &str) -> IResult<&str, usize> {
-    let (remaining, seq) = alphanumeric1(input)?;
-    Ok((remaining, seq.len()))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_literal() {
+        assert_eq!(parse_literal(r#""""#), Ok(("", vec![])));
+        assert_eq!(parse_literal(r#""a b, c!""#), Ok(("", b"a b, c!".to_vec())));
+        assert_eq!(
+            parse_literal(r#""\\\"\'\n\r\t\0\x7f\xFF""#),
+            Ok(("", b"\\\"'\n\r\t\0\x7f\xff".to_vec()))
+        );
+        assert_eq!(parse_literal("\"é\""), Ok(("", "é".as_bytes().to_vec())));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let what = |input: &str| match parse_literal(input) {
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => (input.len() - e.input.len(), e.what),
+            other => panic!("{:?} parsed as {:?}", input, other),
+        };
+        assert_eq!(what("abc"), (0, "an opening quote"));
+        assert_eq!(what(r#""abc"#), (4, "a closing quote"));
+        assert_eq!(what(r#""abc"d"#), (5, "the end of the literal"));
+        assert_eq!(what(r#""a\qb""#), (3, "one of \\ \" ' n r t 0 x"));
+        assert_eq!(what(r#""a\x4""#), (4, "two hex digits"));
+        assert_eq!(what(r#""a\"#), (3, "one of \\ \" ' n r t 0 x"));
+    }
 }
@@ -0,0 +1,18 @@
+use clap::Parser;
+use travelling_santaman::solver::Solver;
+
+/// Finds the shortest route Santa can take through every city
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// File containing the distances, stdin if missing or `-`
+    pub input: Option<String>,
+
+    /// How to look for the route
+    #[arg(long, value_enum, default_value_t = Solver::default())]
+    pub solver: Solver,
+
+    /// Find the longest route instead
+    #[arg(long)]
+    pub longest: bool,
+}
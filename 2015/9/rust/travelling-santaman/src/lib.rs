@@ -0,0 +1,75 @@
+use aoc_common::{Answer, Input, Solution};
+use aoc_graph::Graph;
+
+pub mod solver;
+
+use solver::{Distances, Goal, Solver};
+
+pub struct Puzzle;
+
+impl Puzzle {
+    fn distance(input: &Input, goal: Goal) -> Answer {
+        let lines = input.read_lines()?;
+        let graph: Graph = Graph::parse(&lines)?;
+        let distances = Distances::from(&graph);
+        let solver = match Solver::HeldKarp.max_cities() {
+            Some(max) if distances.cities() > max => Solver::BranchAndBound,
+            _ => Solver::HeldKarp,
+        };
+        let route = solver.solve(&distances, goal);
+        Ok(route
+            .ok_or("no route visits every city")?
+            .distance
+            .to_string())
+    }
+}
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Self::distance(input, Goal::Shortest)
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Self::distance(input, Goal::Longest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_routes() {
+        let lines = [
+            "London to Dublin = 464",
+            "London to Belfast = 518",
+            "Dublin to Belfast = 141",
+        ];
+        let input = Input::embedded(&lines.join("\n"));
+        assert_eq!(Puzzle::part_one(&input).unwrap(), "605");
+        assert_eq!(Puzzle::part_two(&input).unwrap(), "982");
+    }
+
+    #[test]
+    fn test_many_cities() {
+        let lines: Vec<String> = (0..30)
+            .map(|i| format!("C{} to C{} = {}", i, i + 1, i + 1))
+            .collect();
+        let input = Input::embedded(&lines.join("\n"));
+        assert_eq!(Puzzle::part_one(&input).unwrap(), "465");
+        assert_eq!(Puzzle::part_two(&input).unwrap(), "465");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |lines: &str| Puzzle::part_one(&Input::embedded(lines)).unwrap_err();
+        assert_eq!(
+            error("London to = 464").to_string(),
+            "line 1, column 11: expected a name"
+        );
+        assert_eq!(
+            error("London to Dublin = 464\nLondon to Belfast = far").to_string(),
+            "line 2, column 21: expected a weight"
+        );
+    }
+}
@@ -1,129 +1,46 @@
-mod io;
-use crate::io::lines;
-use itertools::Itertools;
-use log::debug;
-use std::collections::HashMap;
-
-#[derive(Debug, Hash, Eq, PartialEq)]
-struct Node {
-    city: String,
-}
-
-#[derive(Debug, Hash, Eq, PartialEq)]
-struct Edge {
-    distance: usize,
-}
-
-#[derive(Debug)]
-struct Graph<'a> {
-    adj: HashMap<&'a Node, HashMap<&'a Node, &'a Edge>>,
-}
-
-impl<'a> Graph<'a> {
-    fn add_edge(&mut self, start: &'a Node, destination: &'a Node, edge: &'a Edge) {
-        self.adj.entry(start).or_default().insert(destination, edge);
-    }
-}
-
-fn parse_line(input: &str) -> Option<(Node, Edge, Node)> {
-    let parts: Vec<&str> = input.split(" = ").collect();
-    if parts.len() != 2 {
-        return None;
-    }
-
-    let left = parts[0].trim();
-    let right = parts[1].trim();
-
-    let trip: Vec<&str> = left.split(" to ").collect();
-    if trip.len() != 2 {
-        return None;
-    }
-
-    let start = trip[0].trim();
-    let destination = trip[1].trim();
+mod args;
 
-    if start.is_empty() || destination.is_empty() {
-        return None;
-    }
+use std::error::Error;
 
-    match right.parse::<usize>() {
-        Err(_) => None,
-        Ok(distance) => Some((
-            Node {
-                city: start.to_owned(),
-            },
-            Edge { distance },
-            Node {
-                city: destination.to_owned(),
-            },
-        )),
-    }
-}
+use aoc_common::{Input, Source};
+use aoc_graph::Graph;
+use args::Args;
+use clap::Parser;
+use log::debug;
+use travelling_santaman::solver::{Distances, Goal};
 
-fn main() {
+fn main() -> Result<(), Box<dyn Error>> {
     env_logger::init();
+    let args = Args::parse();
 
-    let mut graph = Graph {
-        adj: HashMap::new(),
-    };
-    let connections = lines()
-        .map(|line| parse_line(line.as_ref()).expect("Error parsing line"))
-        .collect::<Vec<(Node, Edge, Node)>>();
-
-    for (start, edge, destination) in connections.iter() {
-        graph.add_edge(start, destination, edge);
-        graph.add_edge(destination, start, edge);
-    }
+    let lines = Input::new(Source::from_arg(args.input)).read_lines()?;
+    let graph: Graph = Graph::parse(&lines)?;
 
     debug!("graph = {:?}", graph);
 
-    let nodes = graph.adj.keys();
-    let n = nodes.len();
-
-    let hamiltonian_paths: Vec<Vec<&&Node>> = nodes
-        .permutations(n)
-        .filter(|permutation| {
-            permutation
-                .iter()
-                .tuple_windows()
-                .all(|(source, destination)| {
-                    let neighbors = graph.adj.get(**source).unwrap();
-                    neighbors.contains_key(**destination)
-                })
-        })
-        .collect();
-
-    let result = hamiltonian_paths.iter().map(|hp| {
-        (
-            hp.clone(),
-            hp.iter()
-                .tuple_windows()
-                .fold(0, |acc, (source, destination)| {
-                    acc + graph
-                        .adj
-                        .get(**source)
-                        .unwrap()
-                        .get(**destination)
-                        .unwrap()
-                        .distance
-                }),
-        )
-    });
-
-    let result = if io::part() == "2" {
-        result.max_by_key(|(_, distance)| *distance)
-    } else {
-        result.min_by_key(|(_, distance)| *distance)
+    let distances = Distances::from(&graph);
+    if let Some(max) = args.solver.max_cities() {
+        if graph.len() > max {
+            return Err(format!("{} cities, the solver takes at most {}", graph.len(), max).into());
+        }
+    }
+    let goal = match args.longest {
+        true => Goal::Longest,
+        false => Goal::Shortest,
     };
-
-    let (optimal_path, distance) = result.unwrap();
+    let route = args
+        .solver
+        .solve(&distances, goal)
+        .ok_or("no route visits every city")?;
 
     println!(
         "optimal_path = {:?}",
-        optimal_path
+        route
+            .cities
             .iter()
-            .map(|node| { &node.city })
-            .collect::<Vec<&String>>()
+            .map(|&city| graph.name(city))
+            .collect::<Vec<&str>>()
     );
-    println!("distance = {:?}", distance);
+    println!("distance = {:?}", route.distance);
+    Ok(())
 }
@@ -0,0 +1,557 @@
+//! Ways of finding the shortest, or longest, route that visits every city exactly once.
+//!
+//! Exact solvers always find the best route there is. Heuristics settle for a good one, and
+//! scale to route maps far too large for the exact solvers.
+
+use aoc_graph::Graph;
+use clap::{builder::PossibleValue, ValueEnum};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Most cities [`held_karp`] takes on, its tables growing as `2^n * n`.
+pub const HELD_KARP_MAX_CITIES: usize = 20;
+
+/// Distances between cities, numbered from 0. Roads go both ways.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Distances {
+    cities: usize,
+    roads: Vec<Option<usize>>,
+}
+
+impl Distances {
+    /// `cities` cities, without any road between them.
+    pub fn new(cities: usize) -> Self {
+        Distances {
+            cities,
+            roads: vec![None; cities * cities],
+        }
+    }
+
+    pub fn cities(&self) -> usize {
+        self.cities
+    }
+
+    /// Adds a road between `a` and `b`, replacing any road already there.
+    pub fn set(&mut self, a: usize, b: usize, distance: usize) {
+        self.roads[a * self.cities + b] = Some(distance);
+        self.roads[b * self.cities + a] = Some(distance);
+    }
+
+    /// Length of the road between `a` and `b`, if there is one.
+    pub fn get(&self, a: usize, b: usize) -> Option<usize> {
+        self.roads[a * self.cities + b]
+    }
+
+    /// Total distance along `cities`, if there is a road between each of them and the next.
+    pub fn length(&self, cities: &[usize]) -> Option<usize> {
+        cities
+            .iter()
+            .tuple_windows()
+            .map(|(&a, &b)| self.get(a, b))
+            .sum()
+    }
+
+    /// Roads leaving `city`, with their length.
+    fn roads(&self, city: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.cities)
+            .filter(move |&other| other != city)
+            .filter_map(move |other| self.get(city, other).map(|d| (other, d)))
+    }
+}
+
+/// Every road of `graph`, cities keeping their node ids.
+impl From<&Graph> for Distances {
+    fn from(graph: &Graph) -> Self {
+        let mut distances = Distances::new(graph.len());
+        for (a, b, distance) in graph.edges() {
+            distances.set(a, b, distance);
+        }
+        distances
+    }
+}
+
+/// Whether the route should be as short or as long as possible.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Goal {
+    #[default]
+    Shortest,
+    Longest,
+}
+
+impl Goal {
+    /// Whether a route of distance `a` beats one of distance `b`.
+    pub fn better(self, a: usize, b: usize) -> bool {
+        match self {
+            Goal::Shortest => a < b,
+            Goal::Longest => a > b,
+        }
+    }
+
+    /// The better of two distances.
+    fn best(self, a: usize, b: usize) -> usize {
+        match self.better(b, a) {
+            true => b,
+            false => a,
+        }
+    }
+
+    /// How much worse a route of distance `a` is than one of distance `b`.
+    fn loss(self, a: usize, b: usize) -> f64 {
+        match self {
+            Goal::Shortest => a as f64 - b as f64,
+            Goal::Longest => b as f64 - a as f64,
+        }
+    }
+}
+
+/// Cities in the order they are visited, and the distance travelled.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Route {
+    pub cities: Vec<usize>,
+    pub distance: usize,
+}
+
+impl Route {
+    fn better(self, other: Route, goal: Goal) -> Route {
+        match goal.better(other.distance, self.distance) {
+            true => other,
+            false => self,
+        }
+    }
+}
+
+/// Tries every order of the cities, `n!` of them.
+pub fn brute_force(distances: &Distances, goal: Goal) -> Option<Route> {
+    let n = distances.cities();
+    (0..n)
+        .permutations(n)
+        .filter_map(|cities| {
+            let distance = distances.length(&cities)?;
+            Some(Route { cities, distance })
+        })
+        .reduce(|best, route| best.better(route, goal))
+}
+
+/// Dynamic programming over the sets of cities visited so far and the city the route stops
+/// at, in `O(2^n * n^2)` time.
+///
+/// # Panics
+/// If there are more than [`HELD_KARP_MAX_CITIES`] cities.
+///
+/// # Example
+/// ```rust
+/// use travelling_santaman::solver::{held_karp, Distances, Goal};
+///
+/// let mut distances = Distances::new(3);
+/// distances.set(0, 1, 464);
+/// distances.set(0, 2, 518);
+/// distances.set(1, 2, 141);
+/// let route = held_karp(&distances, Goal::Shortest).unwrap();
+/// assert_eq!((route.cities, route.distance), (vec![2, 1, 0], 605));
+/// assert_eq!(held_karp(&distances, Goal::Longest).unwrap().distance, 982);
+/// ```
+pub fn held_karp(distances: &Distances, goal: Goal) -> Option<Route> {
+    const UNREACHED: u32 = u32::MAX;
+    let n = distances.cities();
+    assert!(
+        n <= HELD_KARP_MAX_CITIES,
+        "{} cities, Held-Karp takes at most {}",
+        n,
+        HELD_KARP_MAX_CITIES
+    );
+    if n == 0 {
+        return Some(Route::default());
+    }
+
+    // best[visited * n + last] is the best distance visiting `visited` and stopping at `last`
+    let sets = 1 << n;
+    let mut best = vec![UNREACHED; sets * n];
+    let mut previous = vec![0u8; sets * n];
+    for city in 0..n {
+        best[(1 << city) * n + city] = 0;
+    }
+    for visited in 1..sets {
+        for last in 0..n {
+            let distance = best[visited * n + last];
+            if distance == UNREACHED {
+                continue;
+            }
+            for (next, road) in distances.roads(last) {
+                if visited & 1 << next != 0 {
+                    continue;
+                }
+                let Some(total) = (distance as usize + road)
+                    .try_into()
+                    .ok()
+                    .filter(|&t| t != UNREACHED)
+                else {
+                    continue;
+                };
+                let slot = (visited | 1 << next) * n + next;
+                if best[slot] == UNREACHED || goal.better(total as usize, best[slot] as usize) {
+                    best[slot] = total;
+                    previous[slot] = last as u8;
+                }
+            }
+        }
+    }
+
+    let all = sets - 1;
+    let mut last = (0..n)
+        .filter(|&last| best[all * n + last] != UNREACHED)
+        .reduce(|a, b| {
+            match goal.better(best[all * n + b] as usize, best[all * n + a] as usize) {
+                true => b,
+                false => a,
+            }
+        })?;
+    let distance = best[all * n + last] as usize;
+    let mut cities = vec![last];
+    let mut visited = all;
+    while visited.count_ones() > 1 {
+        let before = previous[visited * n + last] as usize;
+        visited &= !(1 << last);
+        last = before;
+        cities.push(last);
+    }
+    cities.reverse();
+    Some(Route { cities, distance })
+}
+
+/// Depth first search over partial routes, dropping those that can't beat the best route found
+/// so far even if every city left is reached by its shortest (or longest) road.
+pub fn branch_and_bound(distances: &Distances, goal: Goal) -> Option<Route> {
+    let n = distances.cities();
+    if n < 2 {
+        return brute_force(distances, goal);
+    }
+    // the best any route can do to get to each city
+    let entries: Option<Vec<usize>> = (0..n)
+        .map(|city| {
+            distances
+                .roads(city)
+                .map(|(_, road)| road)
+                .reduce(|a, b| goal.best(a, b))
+        })
+        .collect();
+    let mut search = Search {
+        distances,
+        goal,
+        entries: entries?,
+        best: nearest_neighbour(distances, goal).map(|route| two_opt(distances, goal, route)),
+        route: Vec::with_capacity(n),
+        visited: vec![false; n],
+    };
+    let bound: usize = search.entries.iter().sum();
+    for start in 0..n {
+        search.visit(start, 0, bound - search.entries[start]);
+    }
+    search.best
+}
+
+struct Search<'a> {
+    distances: &'a Distances,
+    goal: Goal,
+    entries: Vec<usize>,
+    best: Option<Route>,
+    route: Vec<usize>,
+    visited: Vec<bool>,
+}
+
+impl Search<'_> {
+    /// Carries on from `city`, having travelled `distance`, with `bound` the best the cities
+    /// left could add to it.
+    fn visit(&mut self, city: usize, distance: usize, bound: usize) {
+        if let Some(best) = &self.best {
+            if !self.goal.better(distance + bound, best.distance) {
+                return;
+            }
+        }
+        self.route.push(city);
+        self.visited[city] = true;
+        if self.route.len() == self.visited.len() {
+            self.best = Some(Route {
+                cities: self.route.clone(),
+                distance,
+            });
+        } else {
+            let mut next: Vec<(usize, usize)> = self
+                .distances
+                .roads(city)
+                .filter(|&(other, _)| !self.visited[other])
+                .collect();
+            // the most promising roads first, to find good routes early
+            match self.goal {
+                Goal::Shortest => next.sort_by_key(|&(_, road)| road),
+                Goal::Longest => next.sort_by_key(|&(_, road)| std::cmp::Reverse(road)),
+            }
+            for (other, road) in next {
+                self.visit(other, distance + road, bound - self.entries[other]);
+            }
+        }
+        self.visited[city] = false;
+        self.route.pop();
+    }
+}
+
+/// Goes from each city to the closest (or furthest) one not visited yet, keeping the best of
+/// the routes starting from every city.
+pub fn nearest_neighbour(distances: &Distances, goal: Goal) -> Option<Route> {
+    let n = distances.cities();
+    if n == 0 {
+        return Some(Route::default());
+    }
+    (0..n)
+        .filter_map(|start| {
+            let mut visited = vec![false; n];
+            visited[start] = true;
+            let mut route = Route {
+                cities: vec![start],
+                distance: 0,
+            };
+            for _ in 1..n {
+                let last = *route.cities.last()?;
+                let (next, road) = distances
+                    .roads(last)
+                    .filter(|&(city, _)| !visited[city])
+                    .reduce(|a, b| match goal.better(b.1, a.1) {
+                        true => b,
+                        false => a,
+                    })?;
+                visited[next] = true;
+                route.cities.push(next);
+                route.distance += road;
+            }
+            Some(route)
+        })
+        .reduce(|best, route| best.better(route, goal))
+}
+
+/// Distance of `cities` once the cities from `i` to `j` are visited the other way round, if
+/// the roads needed for that exist.
+fn reversed(distances: &Distances, route: &Route, i: usize, j: usize) -> Option<usize> {
+    let cities = &route.cities;
+    let road = |a: Option<&usize>, b: Option<&usize>| match (a, b) {
+        (Some(&a), Some(&b)) => distances.get(a, b),
+        _ => Some(0),
+    };
+    let before = i.checked_sub(1).and_then(|i| cities.get(i));
+    let after = cities.get(j + 1);
+    let removed = road(before, cities.get(i))? + road(cities.get(j), after)?;
+    let added = road(before, cities.get(j))? + road(cities.get(i), after)?;
+    Some(route.distance + added - removed)
+}
+
+/// Reverses parts of `route` for as long as that makes it better.
+pub fn two_opt(distances: &Distances, goal: Goal, mut route: Route) -> Route {
+    let n = route.cities.len();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..n {
+            for j in i + 1..n {
+                match reversed(distances, &route, i, j) {
+                    Some(distance) if goal.better(distance, route.distance) => {
+                        route.cities[i..=j].reverse();
+                        route.distance = distance;
+                        improved = true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    route
+}
+
+/// Starts from [`nearest_neighbour`] and reverses random parts of the route, taking worse
+/// routes too while the temperature is high, then polishes the best route seen with
+/// [`two_opt`].
+pub fn annealing(distances: &Distances, goal: Goal, rng: &mut impl Rng) -> Option<Route> {
+    let mut current = nearest_neighbour(distances, goal)?;
+    let n = current.cities.len();
+    let mut best = current.clone();
+    if n > 2 {
+        let roads: Vec<usize> = distances.roads.iter().flatten().copied().collect();
+        let mean = roads.iter().sum::<usize>() as f64 / roads.len().max(1) as f64;
+        let iterations = 20_000 * n;
+        // from about the length of a road down to a thousandth of it
+        let mut temperature = mean.max(1.0);
+        let cooling = 0.001f64.powf(1.0 / iterations as f64);
+        for _ in 0..iterations {
+            let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            let (i, j) = (a.min(b), a.max(b));
+            if i == j {
+                continue;
+            }
+            if let Some(distance) = reversed(distances, &current, i, j) {
+                let loss = goal.loss(distance, current.distance);
+                if loss <= 0.0 || rng.gen::<f64>() < (-loss / temperature).exp() {
+                    current.cities[i..=j].reverse();
+                    current.distance = distance;
+                    if goal.better(current.distance, best.distance) {
+                        best = current.clone();
+                    }
+                }
+            }
+            temperature *= cooling;
+        }
+    }
+    Some(two_opt(distances, goal, best))
+}
+
+/// The solvers the CLI can choose from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Solver {
+    BruteForce,
+    #[default]
+    HeldKarp,
+    BranchAndBound,
+    TwoOpt,
+    Annealing,
+}
+
+impl Solver {
+    pub const ALL: [Solver; 5] = [
+        Solver::BruteForce,
+        Solver::HeldKarp,
+        Solver::BranchAndBound,
+        Solver::TwoOpt,
+        Solver::Annealing,
+    ];
+
+    /// Whether the solver always finds the best route.
+    pub fn exact(self) -> bool {
+        !matches!(self, Solver::TwoOpt | Solver::Annealing)
+    }
+
+    /// Most cities the solver takes on, if there is a limit.
+    pub fn max_cities(self) -> Option<usize> {
+        match self {
+            Solver::HeldKarp => Some(HELD_KARP_MAX_CITIES),
+            _ => None,
+        }
+    }
+
+    /// The best route the solver finds, `None` if no route visits every city.
+    pub fn solve(self, distances: &Distances, goal: Goal) -> Option<Route> {
+        match self {
+            Solver::BruteForce => brute_force(distances, goal),
+            Solver::HeldKarp => held_karp(distances, goal),
+            Solver::BranchAndBound => branch_and_bound(distances, goal),
+            Solver::TwoOpt => {
+                nearest_neighbour(distances, goal).map(|route| two_opt(distances, goal, route))
+            }
+            Solver::Annealing => annealing(distances, goal, &mut StdRng::seed_from_u64(2015)),
+        }
+    }
+}
+
+impl ValueEnum for Solver {
+    fn value_variants<'a>() -> &'a [Self] {
+        &Self::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::BruteForce => {
+                PossibleValue::new("brute-force").help("every order, up to ~11 cities")
+            }
+            Self::HeldKarp => {
+                PossibleValue::new("held-karp").help("dynamic programming, up to 20 cities")
+            }
+            Self::BranchAndBound => {
+                PossibleValue::new("branch-and-bound").help("search dropping hopeless routes")
+            }
+            Self::TwoOpt => PossibleValue::new("two-opt").help("nearest neighbour, then 2-opt"),
+            Self::Annealing => PossibleValue::new("annealing").help("simulated annealing"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `n` cities, with roads of random length between most of them.
+    fn random(n: usize, roads: f64, rng: &mut impl Rng) -> Distances {
+        let mut distances = Distances::new(n);
+        for a in 0..n {
+            for b in a + 1..n {
+                if rng.gen_bool(roads) {
+                    distances.set(a, b, rng.gen_range(1..100));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Makes sure `route` visits every city once and is as long as it says.
+    fn check(distances: &Distances, route: &Route) {
+        assert_eq!(distances.length(&route.cities), Some(route.distance));
+        assert_eq!(
+            route.cities.iter().sorted().copied().collect::<Vec<_>>(),
+            (0..distances.cities()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_exact_solvers() {
+        let mut rng = StdRng::seed_from_u64(9);
+        for n in 0..=7 {
+            for roads in [1.0, 0.6] {
+                let distances = random(n, roads, &mut rng);
+                for goal in [Goal::Shortest, Goal::Longest] {
+                    let expected = brute_force(&distances, goal).map(|r| r.distance);
+                    for solver in [Solver::HeldKarp, Solver::BranchAndBound] {
+                        let route = solver.solve(&distances, goal);
+                        assert_eq!(route.as_ref().map(|r| r.distance), expected, "{:?}", solver);
+                        if let Some(route) = route {
+                            check(&distances, &route);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_larger_maps() {
+        let mut rng = StdRng::seed_from_u64(20);
+        let distances = random(14, 1.0, &mut rng);
+        for goal in [Goal::Shortest, Goal::Longest] {
+            let exact = held_karp(&distances, goal).unwrap();
+            check(&distances, &exact);
+            assert_eq!(
+                branch_and_bound(&distances, goal).unwrap().distance,
+                exact.distance
+            );
+            for solver in [Solver::TwoOpt, Solver::Annealing] {
+                let route = solver.solve(&distances, goal).unwrap();
+                check(&distances, &route);
+                assert!(!goal.better(route.distance, exact.distance));
+            }
+        }
+    }
+
+    #[test]
+    fn test_heuristics() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let distances = random(200, 1.0, &mut rng);
+        let greedy = nearest_neighbour(&distances, Goal::Shortest).unwrap();
+        let improved = two_opt(&distances, Goal::Shortest, greedy.clone());
+        check(&distances, &improved);
+        assert!(improved.distance < greedy.distance);
+
+        // a line of cities, where only going from one end to the other visits them all
+        let mut line = Distances::new(5);
+        for city in 0..4 {
+            line.set(city, city + 1, 1);
+        }
+        for solver in Solver::ALL {
+            let route = solver.solve(&line, Goal::Longest).unwrap();
+            assert_eq!(route.distance, 4, "{:?}", solver);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_common::{Answer, Input, Solution};
+
+#[derive(Debug)]
+pub enum Turn {
+    Right,
+    Left,
+}
+
+impl FromStr for Turn {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let direction = match s {
+            "L" => Turn::Left,
+            "R" => Turn::Right,
+            _ => return Err(()),
+        };
+        Ok(direction)
+    }
+}
+
+#[derive(Debug)]
+pub enum Orientation {
+    East,
+    North,
+    West,
+    South,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub turn: Turn,
+    pub length: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (head, rest) = s.split_at(1);
+        let direction: Turn = head.parse()?;
+        let length = rest.parse().map_err(|_| ())?;
+        Ok(Instruction {
+            turn: direction,
+            length,
+        })
+    }
+}
+
+pub type Point = (i32, i32);
+
+#[derive(Debug)]
+pub struct Location {
+    pub position: Point,
+    pub orientation: Orientation,
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Location {
+            position: (0, 0),
+            orientation: Orientation::North,
+        }
+    }
+}
+
+pub fn step(location: &Location, instruction: &Instruction) -> Location {
+    let displacement = instruction.length as i32;
+    let (x, y) = location.position;
+    match (&location.orientation, &instruction.turn) {
+        (Orientation::East, Turn::Right) => Location {
+            position: (x, y - displacement),
+            orientation: Orientation::South,
+        },
+        (Orientation::East, Turn::Left) => Location {
+            position: (x, y + displacement),
+            orientation: Orientation::North,
+        },
+
+        (Orientation::North, Turn::Right) => Location {
+            position: (x + displacement, y),
+            orientation: Orientation::East,
+        },
+        (Orientation::North, Turn::Left) => Location {
+            position: (x - displacement, y),
+            orientation: Orientation::West,
+        },
+
+        (Orientation::West, Turn::Right) => Location {
+            position: (x, y + displacement),
+            orientation: Orientation::North,
+        },
+        (Orientation::West, Turn::Left) => Location {
+            position: (x, y - displacement),
+            orientation: Orientation::South,
+        },
+
+        (Orientation::South, Turn::Right) => Location {
+            position: (x - displacement, y),
+            orientation: Orientation::West,
+        },
+        (Orientation::South, Turn::Left) => Location {
+            position: (x + displacement, y),
+            orientation: Orientation::East,
+        },
+    }
+}
+
+pub fn parse_instructions(input: &str) -> Option<Vec<Instruction>> {
+    input.trim().split(", ").map(|s| s.parse().ok()).collect()
+}
+
+/// Where following every instruction from the origin ends up.
+pub fn final_location(instructions: &[Instruction]) -> Location {
+    instructions
+        .iter()
+        .fold(Location::default(), |acc, curr| step(&acc, curr))
+}
+
+/// The first point where an instruction ends up for the second time.
+pub fn first_revisited(instructions: &[Instruction]) -> Option<Point> {
+    let mut point_set = HashSet::new();
+    let mut location = Location::default();
+    for instruction in instructions {
+        location = step(&location, instruction);
+        let point = location.position;
+        if point_set.contains(&point) {
+            return Some(point);
+        }
+        point_set.insert(point);
+    }
+    None
+}
+
+/// Manhattan distance from the origin.
+pub fn distance((x, y): Point) -> i32 {
+    x.abs() + y.abs()
+}
+
+pub struct Puzzle;
+
+impl Puzzle {
+    fn instructions(input: &Input) -> Result<Vec<Instruction>, Box<dyn std::error::Error>> {
+        Ok(parse_instructions(&input.read_to_string()?).ok_or("invalid instruction")?)
+    }
+}
+
+impl Solution for Puzzle {
+    const INPUT: Option<&'static str> = Some(include_str!("../input"));
+
+    fn part_one(input: &Input) -> Answer {
+        let instructions = Self::instructions(input)?;
+        Ok(distance(final_location(&instructions).position).to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let instructions = Self::instructions(input)?;
+        let point = first_revisited(&instructions).ok_or("no point is visited twice")?;
+        Ok(distance(point).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let instructions = parse_instructions("R2, L3").unwrap();
+        assert_eq!(distance(final_location(&instructions).position), 5);
+
+        let instructions = parse_instructions("R5, L5, R5, R3").unwrap();
+        assert_eq!(distance(final_location(&instructions).position), 12);
+
+        assert!(parse_instructions("R2, X3").is_none());
+    }
+}
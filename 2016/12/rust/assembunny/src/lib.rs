@@ -0,0 +1,160 @@
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_common::{Answer, Input, Solution};
+
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct Register(pub String);
+
+#[derive(Debug)]
+pub enum Copyable {
+    Value(i32),
+    Reg(Register),
+}
+
+impl FromStr for Copyable {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse::<i32>()
+            .map_or(Copyable::Reg(Register(s.to_owned())), |v| {
+                Copyable::Value(v)
+            }))
+    }
+}
+
+#[derive(Debug)]
+pub enum Jumpable {
+    Value(i32),
+    Reg(Register),
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Copy(Copyable, Register),
+    Inc(Register),
+    Dec(Register),
+    Jnz(Jumpable, i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split(' ').collect();
+        let instruction = match tokens[..] {
+            ["cpy", x, y] => Instruction::Copy(
+                x.parse().expect("invalid copy value"),
+                Register(y.to_string()),
+            ),
+            ["jnz", x, y] => {
+                let jumpable = if let Ok(x) = x.parse::<i32>() {
+                    Jumpable::Value(x)
+                } else {
+                    Jumpable::Reg(Register(x.to_string()))
+                };
+                Instruction::Jnz(jumpable, y.parse().expect("invalid jnz offset"))
+            }
+            ["inc", x] => Instruction::Inc(Register(x.to_string())),
+            ["dec", x] => Instruction::Dec(Register(x.to_string())),
+            _ => panic!("invalid instruction: {}", s),
+        };
+        Ok(instruction)
+    }
+}
+
+/// Executes the program with the registers in `initial` set up front. Every other register
+/// starts out at zero.
+pub fn run<'a>(
+    instructions: &'a [Instruction],
+    initial: &[(&'a str, i32)],
+) -> HashMap<&'a str, i32> {
+    let mut cursor: i32 = 0;
+    let mut state: HashMap<&str, i32> = initial.iter().copied().collect();
+
+    while cursor < instructions.len() as i32 {
+        if cursor < 0 {
+            panic!("underflow!");
+        }
+
+        let instruction = &instructions[cursor as usize];
+
+        let jmp = match instruction {
+            Instruction::Copy(copyable, register) => {
+                let value = match copyable {
+                    Copyable::Value(value) => *value,
+                    Copyable::Reg(from) => *state.get(from.0.as_str()).expect("missing register"),
+                };
+                state.insert(&register.0, value);
+                1
+            }
+            Instruction::Jnz(jumpable, offset) => {
+                let value = match jumpable {
+                    Jumpable::Value(value) => *value,
+                    Jumpable::Reg(register) => *state.entry(&register.0).or_insert(0),
+                };
+                if value != 0 {
+                    *offset
+                } else {
+                    1
+                }
+            }
+            Instruction::Inc(register) => {
+                state
+                    .entry(&register.0)
+                    .and_modify(|value| *value += 1)
+                    .or_insert(1);
+                1
+            }
+            Instruction::Dec(register) => {
+                state
+                    .entry(&register.0)
+                    .and_modify(|value| *value -= 1)
+                    .or_insert(-1);
+                1
+            }
+        };
+        cursor += jmp;
+    }
+
+    state
+}
+
+pub fn parse_program(lines: &[String]) -> Vec<Instruction> {
+    lines
+        .iter()
+        .map(|l| l.parse::<Instruction>().unwrap())
+        .collect()
+}
+
+pub struct Puzzle;
+
+impl Puzzle {
+    fn register_a(input: &Input, c: i32) -> Answer {
+        let instructions = parse_program(&input.read_lines()?);
+        let state = run(&instructions, &[("c", c)]);
+        Ok(state.get("a").copied().unwrap_or(0).to_string())
+    }
+}
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Self::register_a(input, 0)
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Self::register_a(input, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_program() {
+        let lines = ["cpy 41 a", "inc a", "inc a", "dec a", "jnz a 2", "dec a"].map(str::to_string);
+        let instructions = parse_program(&lines);
+        let state = run(&instructions, &[]);
+        assert_eq!(state["a"], 42);
+    }
+}
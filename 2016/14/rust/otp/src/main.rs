@@ -1,11 +1,9 @@
-use std::collections::{HashMap, HashSet, LinkedList};
+use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
-use md5;
 
 fn triplets(hash: &str) -> HashSet<char> {
     hash.chars()
-        .into_iter()
         .tuple_windows()
         .filter(|(a, b, c)| a == b && b == c)
         .map(|(a, _, _)| a)
@@ -14,7 +12,6 @@ fn triplets(hash: &str) -> HashSet<char> {
 
 fn quints(hash: &str) -> HashSet<char> {
     hash.chars()
-        .into_iter()
         .tuple_windows()
         .filter(|(a, b, c, d, e)| a == b && b == c && c == d && d == e)
         .map(|(a, _, _, _, _)| a)
@@ -39,7 +36,7 @@ fn main() {
                 .or_insert_with(|| vec![i]);
 
             let matches = triplet_map.entry(c).or_default();
-            if matches.into_iter().rev().any(|start| i - *start <= 1000) {
+            if matches.iter().rev().any(|start| i - *start <= 1000) {
                 panic!("{i}: {nxt} | {hash} | {:?}", matches);
             }
         }
@@ -0,0 +1,150 @@
+use std::str::FromStr;
+
+use aoc_common::{Answer, Input, Solution};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tile {
+    Safe,
+    Trap,
+}
+
+impl FromStr for Tile {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "." => Ok(Tile::Safe),
+            "^" => Ok(Tile::Trap),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Tile::Safe => '.',
+                Tile::Trap => '^',
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Row(pub Vec<Tile>);
+
+pub struct RowIter {
+    first: bool,
+    curr: Row,
+}
+
+impl IntoIterator for Row {
+    type Item = Row;
+    type IntoIter = RowIter;
+
+    fn into_iter(self) -> RowIter {
+        RowIter {
+            first: true,
+            curr: self,
+        }
+    }
+}
+
+impl FromStr for Row {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .map(|c| c.to_string().parse::<Tile>())
+            .collect::<Result<_, _>>()
+            .map(Row)
+    }
+}
+
+impl std::fmt::Display for Row {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let xs = self.0.iter().map(|t| format!("{}", t)).collect::<Vec<_>>();
+        write!(f, "{}", xs.join(""))
+    }
+}
+
+fn next(r: &Row) -> Row {
+    Row(r
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            let left = i
+                .checked_sub(1)
+                .and_then(|j| r.0.get(j))
+                .unwrap_or(&Tile::Safe);
+            let center = t;
+            let right = r.0.get(i + 1).unwrap_or(&Tile::Safe);
+
+            match (left, center, right) {
+                (Tile::Trap, Tile::Trap, Tile::Safe) => Tile::Trap,
+                (Tile::Safe, Tile::Trap, Tile::Trap) => Tile::Trap,
+                (Tile::Trap, Tile::Safe, Tile::Safe) => Tile::Trap,
+                (Tile::Safe, Tile::Safe, Tile::Trap) => Tile::Trap,
+                _ => Tile::Safe,
+            }
+        })
+        .collect())
+}
+
+impl Iterator for RowIter {
+    type Item = Row;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.first {
+            self.curr = next(&self.curr);
+        }
+
+        self.first = false;
+
+        Some(self.curr.clone())
+    }
+}
+
+/// Number of safe tiles across the first `rows` rows, starting from `row`.
+pub fn count_safe(row: Row, rows: usize) -> usize {
+    row.into_iter()
+        .take(rows)
+        .map(|r| r.0.into_iter().filter(|t| *t == Tile::Safe).count())
+        .sum()
+}
+
+pub struct Puzzle;
+
+impl Puzzle {
+    fn safe_tiles(input: &Input, rows: usize) -> Answer {
+        let row = input
+            .read_to_string()?
+            .trim()
+            .parse::<Row>()
+            .map_err(|_| "invalid row")?;
+        Ok(count_safe(row, rows).to_string())
+    }
+}
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Self::safe_tiles(input, 40)
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Self::safe_tiles(input, 400_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_safe() {
+        assert_eq!(count_safe("..^^.".parse().unwrap(), 3), 6);
+        assert_eq!(count_safe(".^^.^.^^^^".parse().unwrap(), 10), 38);
+    }
+}
@@ -0,0 +1,32 @@
+pub mod models;
+
+use aoc_common::{Answer, Input, Solution};
+use models::{decode, Button, Instruction, Sequence};
+
+pub fn to_sequence(s: String) -> Sequence {
+    s.chars()
+        .map(|c| c.to_string().parse::<Instruction>().unwrap())
+        .collect()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_two(input: &Input) -> Answer {
+        let lines = input.read_lines()?;
+        Ok(decode(Button::Five, lines.into_iter().map(to_sequence))
+            .map(|button| button.to_string())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_code() {
+        let input = Input::embedded("ULL\nRRDDD\nLURDL\nUUUUD");
+        assert_eq!(Puzzle::part_two(&input).unwrap(), "5DB3");
+    }
+}
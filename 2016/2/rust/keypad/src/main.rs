@@ -1,14 +1,9 @@
-mod io;
-mod models;
+use keypad::models::{decode, Button};
+use keypad::to_sequence;
 
-use models::{decode, Button, Instruction, Sequence};
-
-fn to_sequence(s: String) -> Sequence {
-    s.chars()
-        .map(|c| c.to_string().parse::<Instruction>().unwrap())
-        .collect()
-}
-
-fn main() {
-    decode(Button::Five, io::lines().map(to_sequence)).for_each(|button| println!("{:?}", button));
+fn main() -> std::io::Result<()> {
+    let lines = aoc_common::lines()?;
+    decode(Button::Five, lines.into_iter().map(to_sequence))
+        .for_each(|button| println!("{:?}", button));
+    Ok(())
 }
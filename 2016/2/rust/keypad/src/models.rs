@@ -1,3 +1,4 @@
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Debug)]
@@ -42,6 +43,27 @@ pub enum Button {
     D,
 }
 
+impl fmt::Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Button::One => '1',
+            Button::Two => '2',
+            Button::Three => '3',
+            Button::Four => '4',
+            Button::Five => '5',
+            Button::Six => '6',
+            Button::Seven => '7',
+            Button::Eight => '8',
+            Button::Nine => '9',
+            Button::A => 'A',
+            Button::B => 'B',
+            Button::C => 'C',
+            Button::D => 'D',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
 fn step(button: Button, instruction: Instruction) -> Button {
     match button {
         Button::One => match instruction {
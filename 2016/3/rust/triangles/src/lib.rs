@@ -1,3 +1,8 @@
+use aoc_common::{Answer, Input, Solution};
+use itertools::Itertools;
+
+pub mod utils;
+
 pub fn can_make_triangle(a: usize, b: usize, c: usize) -> bool {
     a < (b + c) && b < (a + c) && c < (a + b)
 }
@@ -20,6 +25,60 @@ pub fn vertice(a: usize, b: usize, c: usize) -> Point {
     )
 }
 
+pub fn read_k<const K: usize>(s: String) -> [usize; K] {
+    s.split_whitespace()
+        .filter_map(|s| s.parse::<usize>().ok())
+        // .take(3)
+        .collect::<Vec<usize>>()
+        .try_into()
+        .expect("Expected 3 lengths")
+}
+
+pub fn read_n(s: String) -> Vec<usize> {
+    s.split_whitespace()
+        .filter_map(|s| s.parse::<usize>().ok())
+        .collect::<Vec<usize>>()
+}
+
+/// Counts the triangles listed one per row.
+pub fn count_row_triangles(lines: Vec<String>) -> usize {
+    lines
+        .into_iter()
+        .map(read_k::<3>)
+        .filter(|[a, b, c]| can_make_triangle(*a, *b, *c))
+        .count()
+}
+
+/// Counts the triangles listed down the columns, three rows at a time.
+pub fn count_column_triangles(lines: Vec<String>) -> usize {
+    lines
+        .into_iter()
+        .map(read_n)
+        .chunks(3)
+        .into_iter()
+        .map(|rows| rows.collect::<Vec<Vec<usize>>>())
+        .flat_map(utils::transpose)
+        .filter(|sides| {
+            let [a, b, c]: [usize; 3] = sides[..]
+                .try_into()
+                .unwrap_or_else(|_| panic!("Needed 3 lines but only got {}", sides.len()));
+            can_make_triangle(a, b, c)
+        })
+        .count()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Ok(count_row_triangles(input.read_lines()?).to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Ok(count_column_triangles(input.read_lines()?).to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -29,4 +88,12 @@ mod tests {
         assert!(can_make_triangle(3, 4, 5));
         assert!(!can_make_triangle(5, 10, 25));
     }
+
+    #[test]
+    fn test_column_triangles() {
+        let lines = ["101 301 501", "102 302 502", "103 303 503"]
+            .map(str::to_string)
+            .to_vec();
+        assert_eq!(count_column_triangles(lines), 3);
+    }
 }
@@ -0,0 +1,81 @@
+pub mod models;
+mod parsers;
+
+use aoc_common::{Answer, Input, Solution};
+use models::Room;
+
+const LETTERS: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+
+pub fn decode(s: &str, sector_id: usize) -> String {
+    assert!(sector_id != 0, "sector_id cannot be zero");
+    s.chars()
+        .map(|c| {
+            if c == '-' {
+                return ' ';
+            }
+            let offset = c as usize - 97;
+            let index = offset + sector_id;
+            LETTERS[index % 26]
+            // let step = offset + sector_id + 1;
+            // ('a'..='z').cycle().take(step).last().unwrap()
+        })
+        .collect::<String>()
+}
+
+pub fn real_rooms(input: &Input) -> Result<Vec<Room>, Box<dyn std::error::Error>> {
+    let mut rooms = Vec::new();
+    for line in input.clone().comments("--").read_lines()? {
+        let room = line
+            .parse::<Room>()
+            .map_err(|e| format!("{}: {}", line, e.0))?;
+        if room.is_real() {
+            rooms.push(room);
+        }
+    }
+    Ok(rooms)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        let rooms = real_rooms(input)?;
+        Ok(rooms
+            .iter()
+            .map(|room| room.sector_id)
+            .sum::<usize>()
+            .to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        let room = real_rooms(input)?
+            .into_iter()
+            .find(|room| decode(&room.name, room.sector_id) == "northpole object storage")
+            .ok_or("could not find the north pole objects")?;
+        Ok(room.sector_id.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("qzmt-zixmtkozy-ivhz", 343), "very encrypted name");
+    }
+
+    #[test]
+    fn test_real_rooms() {
+        let input = Input::embedded(
+            "aaaaa-bbb-z-y-x-123[abxyz]\n\
+            a-b-c-d-e-f-g-h-987[abcde]\n\
+            not-a-real-room-404[oarel]\n\
+            totally-real-room-200[decoy]",
+        );
+        assert_eq!(Puzzle::part_one(&input).unwrap(), "1514");
+    }
+}
@@ -6,7 +6,7 @@ use std::str::FromStr;
 use crate::parsers::{parse_checksum, parse_name, parse_usize};
 
 #[derive(Debug)]
-pub struct ParseRoomError(String);
+pub struct ParseRoomError(pub String);
 
 #[derive(Debug)]
 pub struct Room {
@@ -36,8 +36,7 @@ impl Room {
 
         // TODO: a most_common iterator for strings that yields sorted characters
         // self.name.most_common().take(5)
-        let mut top =
-            BinaryHeap::from_iter(count_char_map.into_iter().map(|(count, c)| (count, c)));
+        let mut top = BinaryHeap::from_iter(count_char_map);
 
         let mut checksum = Vec::new();
         while checksum.len() < 5 {
@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Input, Solution};
+use itertools::Itertools;
+
+pub struct PasswordCracker {
+    door_id: String,
+    curr_index: i32,
+}
+
+impl PasswordCracker {
+    pub fn new(door_id: String) -> Self {
+        PasswordCracker {
+            door_id,
+            curr_index: 0,
+        }
+    }
+}
+
+impl Iterator for PasswordCracker {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for index in self.curr_index.. {
+            let s = format!("{}{}", self.door_id, index);
+            let hash = format!("{:x}", md5::compute(s));
+            if hash.starts_with("00000") {
+                let c = hash.chars().nth(5).unwrap();
+                self.curr_index = index + 1;
+                return Some(c);
+            }
+        }
+        None
+    }
+}
+
+pub fn crack_password(door_id: &str) -> String {
+    PasswordCracker::new(door_id.to_string()).take(8).collect()
+}
+
+pub fn crack_password_2(door_id: &str) -> Option<String> {
+    let mut password = [' '; 8];
+    let mut pending: HashSet<_> = (0..8).collect();
+    for index in 0.. {
+        let s = format!("{}{}", door_id, index);
+        let hash = format!("{:x}", md5::compute(s));
+        if !hash.starts_with("00000") {
+            continue;
+        }
+        let (position, c) = hash.chars().skip(5).take(2).next_tuple().unwrap();
+        if let Ok(position) = position.to_string().parse::<usize>() {
+            if position >= 8 {
+                continue;
+            }
+            if pending.contains(&position) {
+                password[position] = c;
+                pending.remove(&position);
+            }
+            if pending.is_empty() {
+                return Some(password.iter().collect());
+            }
+        }
+    }
+    None
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Ok(crack_password(input.read_to_string()?.trim()))
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        crack_password_2(input.read_to_string()?.trim()).ok_or_else(|| "ran out of indices".into())
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use aoc_common::{Answer, Input, Solution};
+
+pub mod utils;
+
+pub fn to_counter<T: Hash + Eq>(vec: Vec<T>) -> HashMap<T, usize> {
+    let mut counter = HashMap::new();
+    vec.into_iter().for_each(|c| {
+        let count = counter.entry(c).or_insert(0_usize);
+        *count += 1;
+    });
+    counter
+}
+
+/// Recovers the message by picking, for every column, either its most or its least common
+/// character.
+pub fn error_correct(lines: Vec<String>, most_common: bool) -> String {
+    utils::transpose(
+        lines
+            .into_iter()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .collect(),
+    )
+    .into_iter()
+    .map(to_counter)
+    .map(|count_map| {
+        let counts = count_map.into_iter();
+        let (c, _) = if most_common {
+            counts.max_by_key(|&(_, count)| count)
+        } else {
+            counts.min_by_key(|&(_, count)| count)
+        }
+        .unwrap();
+        c
+    })
+    .collect()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Ok(error_correct(input.read_lines()?, true))
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Ok(error_correct(input.read_lines()?, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "eedadn\ndrvtee\neandsr\nraavrd\natevrs\ntsrnev\nsdttsa\nrasrtv\n\
+        nssdts\nntnada\nsvetve\ntesnvt\nvntsnd\nvrdear\ndvrsen\nenarar";
+
+    #[test]
+    fn test_error_correct() {
+        let input = Input::embedded(SAMPLE);
+        assert_eq!(Puzzle::part_one(&input).unwrap(), "easter");
+        assert_eq!(Puzzle::part_two(&input).unwrap(), "advent");
+    }
+}
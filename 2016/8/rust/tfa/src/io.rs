@@ -1,4 +1,3 @@
-use std::io::BufRead;
 use std::thread::sleep;
 use std::time::Duration;
 
@@ -11,7 +10,3 @@ pub fn flash(s: &str, duration_in_millis: u64) {
     sleep(Duration::from_millis(duration_in_millis));
     clear();
 }
-
-pub fn lines() -> impl Iterator<Item = String> {
-    std::io::stdin().lock().lines().map_while(Result::ok)
-}
@@ -3,6 +3,7 @@ use std::str::FromStr;
 
 mod parsers;
 
+use aoc_common::{Answer, Input, Solution};
 use nom::branch::alt;
 use parsers::{parse_column_rotation, parse_rect, parse_row_rotation};
 
@@ -32,14 +33,15 @@ pub enum Instruction {
 }
 
 #[derive(Debug)]
-pub struct InstructionParseError(String);
+pub struct InstructionParseError(pub String);
 
 impl FromStr for Instruction {
     type Err = InstructionParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut parser = alt((parse_rect, parse_row_rotation, parse_column_rotation));
 
-        let (_, instruction) = parser(s).map_err(|_| InstructionParseError("err".to_string()))?;
+        let (_, instruction) =
+            parser(s).map_err(|_| InstructionParseError("invalid instruction".to_string()))?;
         Ok(instruction)
     }
 }
@@ -73,6 +75,14 @@ impl<const C: usize, const R: usize> Grid<C, R> {
             }
         }
     }
+
+    /// Number of pixels that are on.
+    pub fn lit(&self) -> usize {
+        self.0
+            .iter()
+            .flat_map(|row| row.iter().filter(|c| **c))
+            .count()
+    }
 }
 
 impl<const C: usize, const R: usize> std::fmt::Display for Grid<C, R> {
@@ -99,6 +109,31 @@ impl<const C: usize, const R: usize> std::fmt::Display for Grid<C, R> {
     }
 }
 
+pub struct Puzzle;
+
+impl Puzzle {
+    fn screen(input: &Input) -> Result<Grid<50, 6>, Box<dyn std::error::Error>> {
+        let mut grid = Grid([[false; 50]; 6]);
+        for line in input.read_lines()? {
+            let instruction = line
+                .parse::<Instruction>()
+                .map_err(|e| format!("{}: {}", line, e.0))?;
+            grid.apply(&instruction);
+        }
+        Ok(grid)
+    }
+}
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Ok(Self::screen(input)?.lit().to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Ok(Self::screen(input)?.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,6 +156,7 @@ mod tests {
             height: 2,
         }));
         assert_eq!(grid.0, [[true, true, true], [true, true, true]]);
+        assert_eq!(grid.lit(), 6);
     }
 
     #[test]
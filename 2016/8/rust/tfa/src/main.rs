@@ -1,3 +1,4 @@
+use aoc_common::{Input, Source};
 use tfa::Grid;
 
 mod args;
@@ -5,7 +6,7 @@ mod io;
 
 use args::Cli;
 
-fn main() {
+fn main() -> std::io::Result<()> {
     let args = Cli::new();
 
     let mut grid = Grid([[false; 50]; 6]);
@@ -19,7 +20,9 @@ fn main() {
         _ => None,
     };
 
-    io::lines()
+    Input::new(Source::Stdin)
+        .read_lines()?
+        .into_iter()
         .map(|line| line.parse().expect("invalid instruction"))
         .for_each(|instruction| {
             grid.apply(&instruction);
@@ -30,11 +33,6 @@ fn main() {
 
     println!("{grid}");
 
-    let count = grid
-        .0
-        .into_iter()
-        .flat_map(|row| row.into_iter().filter(|c| *c))
-        .count();
-
-    println!("{count}");
+    println!("{}", grid.lit());
+    Ok(())
 }
@@ -2,6 +2,7 @@ mod parsers;
 
 use std::{fmt::Display, str::FromStr};
 
+use aoc_common::{Answer, Input, Solution};
 use nom::Parser;
 use parsers::parse_marker;
 
@@ -152,6 +153,18 @@ pub fn decompress_up_to(s: &str, max_depth: usize) -> String {
     tree.decompress()
 }
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    fn part_one(input: &Input) -> Answer {
+        Ok(decoded_count_up_to(input.read_to_string()?.trim(), 1).to_string())
+    }
+
+    fn part_two(input: &Input) -> Answer {
+        Ok(decoded_count(input.read_to_string()?.trim()).to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,14 +1,15 @@
+use aoc_common::{Input, Source};
 use clap::Parser;
 
 mod args;
-mod io;
 
 use args::Command;
 
-fn main() {
+fn main() -> std::io::Result<()> {
     let cli = args::Cli::parse();
 
-    for line in io::lines() {
+    for line in Input::new(Source::Stdin).lines()? {
+        let line = line?;
         match cli.command {
             Command::Decompress => {
                 println!("{}", decompress(cli.depth)(&line));
@@ -18,6 +19,7 @@ fn main() {
             }
         }
     }
+    Ok(())
 }
 
 fn decompress(depth: Option<usize>) -> impl Fn(&str) -> String {
@@ -0,0 +1,216 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::PathBuf;
+
+/// Where the puzzle input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    Embedded(String),
+}
+
+impl Source {
+    /// Picks the source named by the first CLI argument.
+    pub fn from_args() -> Self {
+        Self::from_arg(std::env::args().nth(1))
+    }
+
+    /// A missing argument, `-` or `--` all mean stdin. Anything else is a file path.
+    ///
+    /// # Example
+    /// ```rust
+    /// use aoc_common::Source;
+    ///
+    /// assert_eq!(Source::from_arg(None), Source::Stdin);
+    /// assert_eq!(Source::from_arg(Some("--".to_string())), Source::Stdin);
+    /// assert_eq!(
+    ///     Source::from_arg(Some("input".to_string())),
+    ///     Source::File("input".into())
+    /// );
+    /// ```
+    pub fn from_arg(arg: Option<String>) -> Self {
+        match arg.as_deref() {
+            None | Some("-") | Some("--") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::File(path) => {
+                Box::new(BufReader::new(File::open(path).map_err(|e| {
+                    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+                })?))
+            }
+            Source::Embedded(s) => Box::new(Cursor::new(s.clone().into_bytes())),
+        })
+    }
+}
+
+/// Puzzle input along with the rules for which of its lines are worth handing to a solver.
+#[derive(Debug, Clone)]
+pub struct Input {
+    source: Source,
+    comment_prefix: Option<String>,
+    skip_blank: bool,
+}
+
+impl Input {
+    pub fn new(source: Source) -> Self {
+        Input {
+            source,
+            comment_prefix: None,
+            skip_blank: false,
+        }
+    }
+
+    pub fn from_args() -> Self {
+        Self::new(Source::from_args())
+    }
+
+    pub fn embedded(s: &str) -> Self {
+        Self::new(Source::Embedded(s.to_string()))
+    }
+
+    /// Drops every line that starts with `prefix`, ignoring leading whitespace.
+    pub fn comments(mut self, prefix: &str) -> Self {
+        self.comment_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Drops every line made up of whitespace only.
+    pub fn skip_blank(mut self) -> Self {
+        self.skip_blank = true;
+        self
+    }
+
+    /// Returns an iterator over the lines that survive the comment and blank line filters.
+    ///
+    /// # Example
+    /// ```rust
+    /// use aoc_common::Input;
+    ///
+    /// let lines = Input::embedded("a\n-- b\n\nc")
+    ///     .comments("--")
+    ///     .skip_blank()
+    ///     .lines()
+    ///     .unwrap()
+    ///     .collect::<std::io::Result<Vec<_>>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(lines, vec!["a", "c"]);
+    /// ```
+    pub fn lines(&self) -> io::Result<Lines> {
+        Ok(Lines {
+            inner: self.source.open()?.lines(),
+            comment_prefix: self.comment_prefix.clone(),
+            skip_blank: self.skip_blank,
+        })
+    }
+
+    /// Collects the filtered lines, stopping at the first read error.
+    pub fn read_lines(&self) -> io::Result<Vec<String>> {
+        self.lines()?.collect()
+    }
+
+    /// Reads the filtered input back into a single newline separated string.
+    pub fn read_to_string(&self) -> io::Result<String> {
+        if self.comment_prefix.is_none() && !self.skip_blank {
+            let mut s = String::new();
+            self.source.open()?.read_to_string(&mut s)?;
+            return Ok(s);
+        }
+        Ok(self.read_lines()?.join("\n"))
+    }
+}
+
+/// Iterator over the lines of an [`Input`].
+pub struct Lines {
+    inner: io::Lines<Box<dyn BufRead>>,
+    comment_prefix: Option<String>,
+    skip_blank: bool,
+}
+
+impl Lines {
+    fn keep(&self, line: &str) -> bool {
+        if self.skip_blank && line.trim().is_empty() {
+            return false;
+        }
+        match &self.comment_prefix {
+            Some(prefix) => !line.trim_start().starts_with(prefix.as_str()),
+            None => true,
+        }
+    }
+}
+
+impl Iterator for Lines {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next()? {
+                Ok(line) if !self.keep(&line) => continue,
+                result => return Some(result),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_lines() {
+        let input = Input::embedded("1\n2\n\n3\n");
+        assert_eq!(input.read_lines().unwrap(), vec!["1", "2", "", "3"]);
+        assert_eq!(
+            input.skip_blank().read_lines().unwrap(),
+            vec!["1", "2", "3"]
+        );
+    }
+
+    #[test]
+    fn test_comments() {
+        let input =
+            Input::embedded("123 -> x\n-- NOT b -> p\n  -- indented\nx -> y").comments("--");
+        assert_eq!(input.read_lines().unwrap(), vec!["123 -> x", "x -> y"]);
+    }
+
+    #[test]
+    fn test_read_to_string() {
+        assert_eq!(
+            Input::embedded("[1,2]\n").read_to_string().unwrap(),
+            "[1,2]\n"
+        );
+        assert_eq!(
+            Input::embedded("# header\n[1,2]\n")
+                .comments("#")
+                .read_to_string()
+                .unwrap(),
+            "[1,2]"
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = Input::new(Source::File("does/not/exist".into()))
+            .read_lines()
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().starts_with("does/not/exist"));
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let lines = Lines {
+            inner: (Box::new(Cursor::new(vec![b'a', b'\n', 0xff])) as Box<dyn BufRead>).lines(),
+            comment_prefix: None,
+            skip_blank: false,
+        };
+        let err = lines.collect::<io::Result<Vec<_>>>().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,13 @@
+pub mod input;
+pub mod parsing;
+pub mod solution;
+
+pub use input::{Input, Lines, Source};
+pub use solution::{Answer, Solution, Unsolved};
+
+/// Reads every line of the puzzle input named by the first CLI argument, or of stdin when no
+/// argument has been passed.
+/// A line that can't be read fails the whole call rather than being skipped.
+pub fn lines() -> std::io::Result<Vec<String>> {
+    Input::from_args().read_lines()
+}
@@ -0,0 +1,55 @@
+//! Errors for nom parsers that say what they were expecting, and where.
+
+use nom::error::{ErrorKind, FromExternalError, ParseError};
+use nom::IResult;
+
+/// Where the input stopped making sense, and what should have been there instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected<'a> {
+    pub input: &'a str,
+    pub what: &'static str,
+}
+
+impl<'a> ParseError<&'a str> for Expected<'a> {
+    fn from_error_kind(input: &'a str, _: ErrorKind) -> Self {
+        Expected {
+            input,
+            what: "something else",
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Expected<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        Expected::from_error_kind(input, kind)
+    }
+}
+
+pub type Parsed<'a, T> = IResult<&'a str, T, Expected<'a>>;
+
+/// Replaces recoverable errors of `parser` with `what`, pointing at where it started.
+///
+/// # Example
+/// ```rust
+/// use aoc_common::parsing::{expect, Expected, Parsed};
+/// use nom::character::complete::digit1;
+///
+/// let mut digits = expect("a number", digit1);
+/// let parsed: Parsed<&str> = digits("x1");
+/// assert_eq!(parsed, Err(nom::Err::Error(Expected { input: "x1", what: "a number" })));
+/// ```
+pub fn expect<'a, O>(
+    what: &'static str,
+    mut parser: impl FnMut(&'a str) -> Parsed<'a, O>,
+) -> impl FnMut(&'a str) -> Parsed<'a, O> {
+    move |input| {
+        parser(input).map_err(|e| match e {
+            nom::Err::Error(_) => nom::Err::Error(Expected { input, what }),
+            e => e,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::Input;
+
+/// The answer to one part of a puzzle, or whatever kept us from getting to it.
+pub type Answer = Result<String, Box<dyn Error>>;
+
+/// Error for the parts a day crate has no solution for.
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "this part has not been solved")
+    }
+}
+
+impl Error for Unsolved {}
+
+/// A single day's puzzle. Every day crate exposes one implementation of it so that the `aoc`
+/// runner can solve any part of any puzzle the same way.
+pub trait Solution {
+    /// Input to fall back on when the runner is not handed one. Meant for puzzles whose input
+    /// is a short string rather than a file.
+    const INPUT: Option<&'static str> = None;
+
+    fn part_one(_input: &Input) -> Answer {
+        Err(Box::new(Unsolved))
+    }
+
+    fn part_two(_input: &Input) -> Answer {
+        Err(Box::new(Unsolved))
+    }
+}
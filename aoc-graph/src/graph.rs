@@ -0,0 +1,368 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::parsers::{parse_edge, ParseError};
+
+/// Position of a node in the order it was added to its graph.
+pub type NodeId = usize;
+
+/// Whether an edge can be followed both ways.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Direction {
+    Directed,
+    #[default]
+    Undirected,
+}
+
+/// Nodes, known by name, and weighted edges between them.
+///
+/// # Example
+/// ```rust
+/// use aoc_graph::Graph;
+///
+/// let lines = ["London to Dublin = 464", "London to Belfast = 518", "Dublin to Belfast = 141"];
+/// let graph: Graph = Graph::parse(&lines).unwrap();
+/// let (london, belfast) = (graph.id("London").unwrap(), graph.id("Belfast").unwrap());
+/// assert_eq!(graph.weight(belfast, london), Some(518));
+/// assert_eq!(graph.shortest_path(london, belfast), Some((518, vec![london, belfast])));
+/// assert!(graph.is_connected());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W = usize> {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    /// The edges leaving each node, and their weight.
+    edges: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Graph {
+            names: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<W: Copy> Graph<W> {
+    pub fn new() -> Self {
+        Graph::default()
+    }
+
+    /// Parses one undirected edge per line, like `London to Dublin = 464`.
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Result<Self, ParseError>
+    where
+        W: FromStr,
+    {
+        Self::parse_with(lines, Direction::Undirected)
+    }
+
+    /// Same as [`Graph::parse`], with edges going from the first node to the second one only
+    /// if they are directed.
+    pub fn parse_with<S: AsRef<str>>(lines: &[S], direction: Direction) -> Result<Self, ParseError>
+    where
+        W: FromStr,
+    {
+        let mut graph = Graph::new();
+        for (i, line) in lines.iter().enumerate() {
+            let (from, to, weight) = parse_edge(line.as_ref()).map_err(|e| e.on_line(i + 1))?;
+            let (from, to) = (graph.add_node(from), graph.add_node(to));
+            graph.add_edge(from, to, weight, direction);
+        }
+        Ok(graph)
+    }
+
+    /// Id of the node called `name`, adding it if there is none.
+    pub fn add_node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(vec![]);
+        id
+    }
+
+    /// Adds an edge from `from` to `to`, and back if it is undirected, replacing any edge
+    /// already going the same way.
+    ///
+    /// # Panics
+    /// If either node isn't in the graph.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W, direction: Direction) {
+        self.insert(from, to, weight);
+        if direction == Direction::Undirected {
+            self.insert(to, from, weight);
+        }
+    }
+
+    fn insert(&mut self, from: NodeId, to: NodeId, weight: W) {
+        let edges = &mut self.edges[from];
+        match edges.iter_mut().find(|(node, _)| *node == to) {
+            Some(edge) => edge.1 = weight,
+            None => edges.push((to, weight)),
+        }
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Every node, in the order they were added.
+    pub fn nodes(&self) -> std::ops::Range<NodeId> {
+        0..self.len()
+    }
+
+    /// Edges leaving `node`, with their weight.
+    pub fn neighbors(&self, node: NodeId) -> impl Iterator<Item = (NodeId, W)> + '_ {
+        self.edges[node].iter().copied()
+    }
+
+    /// Every edge, once for each way it can be followed.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId, W)> + '_ {
+        self.nodes()
+            .flat_map(move |from| self.neighbors(from).map(move |(to, w)| (from, to, w)))
+    }
+
+    /// Weight of the edge from `from` to `to`, if there is one.
+    pub fn weight(&self, from: NodeId, to: NodeId) -> Option<W> {
+        self.neighbors(from)
+            .find(|&(node, _)| node == to)
+            .map(|(_, weight)| weight)
+    }
+
+    /// Nodes that can reach each other ignoring which way edges go, each group and the nodes in
+    /// it in the order they were added.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let mut sets = DisjointSets::new(self.len());
+        for (from, to, _) in self.edges() {
+            sets.union(from, to);
+        }
+        let mut components: Vec<Vec<NodeId>> = vec![];
+        let mut index = HashMap::new();
+        for node in self.nodes() {
+            let root = sets.find(node);
+            let i = *index.entry(root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[i].push(node);
+        }
+        components
+    }
+
+    /// Whether every node can reach every other one, ignoring which way edges go.
+    pub fn is_connected(&self) -> bool {
+        self.components().len() <= 1
+    }
+
+    /// Whether every node can reach every other one following edges the way they go.
+    pub fn is_strongly_connected(&self) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let mut reversed = vec![vec![]; self.len()];
+        for (from, to, _) in self.edges() {
+            reversed[to].push(from);
+        }
+        let forward: Vec<Vec<NodeId>> = self
+            .edges
+            .iter()
+            .map(|edges| edges.iter().map(|&(to, _)| to).collect())
+            .collect();
+        reaches_all(&forward) && reaches_all(&reversed)
+    }
+}
+
+impl<W: Copy + Ord + Add<Output = W> + Default> Graph<W> {
+    /// Lightest path from `from` to `to`, and its weight, using Dijkstra's algorithm. Weights
+    /// must not be negative.
+    pub fn shortest_path(&self, from: NodeId, to: NodeId) -> Option<(W, Vec<NodeId>)> {
+        let mut best: Vec<Option<W>> = vec![None; self.len()];
+        let mut previous = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((W::default(), from))]);
+        best[from] = Some(W::default());
+        while let Some(Reverse((weight, node))) = queue.pop() {
+            if node == to {
+                break;
+            }
+            if best[node].is_some_and(|best| best < weight) {
+                continue;
+            }
+            for (next, edge) in self.neighbors(node) {
+                let total = weight + edge;
+                if best[next].is_none_or(|best| total < best) {
+                    best[next] = Some(total);
+                    previous[next] = Some(node);
+                    queue.push(Reverse((total, next)));
+                }
+            }
+        }
+
+        let weight = best[to]?;
+        let mut path = vec![to];
+        while let Some(node) = previous[*path.last()?] {
+            path.push(node);
+        }
+        path.reverse();
+        Some((weight, path))
+    }
+
+    /// Lightest edges joining every node that can be reached from another, using Kruskal's
+    /// algorithm and ignoring which way edges go. Disconnected graphs get a tree per component.
+    pub fn minimum_spanning_tree(&self) -> Vec<(NodeId, NodeId, W)> {
+        let mut edges: Vec<_> = self.edges().collect();
+        edges.sort_by_key(|&(from, to, weight)| (weight, from.min(to), from.max(to)));
+        let mut sets = DisjointSets::new(self.len());
+        edges
+            .into_iter()
+            .filter(|&(from, to, _)| sets.union(from, to))
+            .collect()
+    }
+}
+
+/// Whether following `edges` from the first node gets to every node.
+fn reaches_all(edges: &[Vec<NodeId>]) -> bool {
+    let mut seen = vec![false; edges.len()];
+    let mut stack = vec![0];
+    seen[0] = true;
+    while let Some(node) = stack.pop() {
+        for &next in &edges[node] {
+            if !seen[next] {
+                seen[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+    seen.into_iter().all(|seen| seen)
+}
+
+/// Union-find over nodes, with path halving.
+struct DisjointSets {
+    parents: Vec<NodeId>,
+}
+
+impl DisjointSets {
+    fn new(n: usize) -> Self {
+        DisjointSets {
+            parents: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, mut node: NodeId) -> NodeId {
+        while self.parents[node] != node {
+            self.parents[node] = self.parents[self.parents[node]];
+            node = self.parents[node];
+        }
+        node
+    }
+
+    /// Joins the sets of `a` and `b`, returning whether they were apart.
+    fn union(&mut self, a: NodeId, b: NodeId) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parents[a] = b;
+        a != b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(lines: &[&str], direction: Direction) -> Graph {
+        Graph::parse_with(lines, direction).unwrap()
+    }
+
+    #[test]
+    fn test_edges() {
+        let mut graph = graph(&["a to b = 1", "b to c = 2"], Direction::Directed);
+        let (a, b, c) = (0, 1, 2);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.name(c), "c");
+        assert_eq!(graph.weight(a, b), Some(1));
+        assert_eq!(graph.weight(b, a), None);
+
+        graph.add_edge(b, a, 5, Direction::Undirected);
+        assert_eq!(graph.weight(a, b), Some(5));
+        assert_eq!(graph.edges().count(), 3);
+        let d = graph.add_node("d");
+        assert_eq!(graph.add_node("d"), d);
+        assert_eq!(graph.neighbors(d).count(), 0);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let lines = [
+            "a to b = 7",
+            "a to c = 9",
+            "a to f = 14",
+            "b to c = 10",
+            "b to d = 15",
+        ];
+        let more = ["c to d = 11", "c to f = 2", "d to e = 6", "e to f = 9"];
+        let graph = graph(&[&lines[..], &more[..]].concat(), Direction::Undirected);
+        let id = |name| graph.id(name).unwrap();
+        let names = |path: Vec<NodeId>| path.into_iter().map(|n| graph.name(n)).collect::<Vec<_>>();
+
+        let (weight, path) = graph.shortest_path(id("a"), id("e")).unwrap();
+        assert_eq!((weight, names(path)), (20, vec!["a", "c", "f", "e"]));
+        assert_eq!(
+            graph.shortest_path(id("d"), id("d")),
+            Some((0, vec![id("d")]))
+        );
+
+        let directed = self::graph(&lines, Direction::Directed);
+        assert_eq!(directed.shortest_path(1, 0), None);
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let graph = graph(
+            &[
+                "a to b = 4",
+                "a to c = 1",
+                "b to c = 2",
+                "c to d = 5",
+                "b to d = 8",
+                "e to f = 3",
+            ],
+            Direction::Undirected,
+        );
+        let tree = graph.minimum_spanning_tree();
+        assert_eq!(tree.len(), 4);
+        assert_eq!(
+            tree.iter().map(|&(_, _, w)| w).sum::<usize>(),
+            1 + 2 + 5 + 3
+        );
+    }
+
+    #[test]
+    fn test_connectivity() {
+        let mut graph = graph(&["a to b = 1", "b to c = 1"], Direction::Directed);
+        assert!(graph.is_connected());
+        assert!(!graph.is_strongly_connected());
+        graph.add_edge(2, 0, 1, Direction::Directed);
+        assert!(graph.is_strongly_connected());
+
+        graph.add_node("d");
+        assert!(!graph.is_connected());
+        assert_eq!(graph.components(), vec![vec![0, 1, 2], vec![3]]);
+        assert!(Graph::<usize>::new().is_strongly_connected());
+    }
+}
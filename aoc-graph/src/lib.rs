@@ -0,0 +1,7 @@
+//! Weighted graphs of named nodes, shared by the puzzles that are about routes between places.
+
+mod graph;
+mod parsers;
+
+pub use graph::{Direction, Graph, NodeId};
+pub use parsers::{parse_edge, ParseError};
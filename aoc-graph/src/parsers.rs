@@ -0,0 +1,95 @@
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_common::parsing::{expect, Parsed};
+use nom::bytes::complete::{tag, take_until1};
+use nom::character::complete::{not_line_ending, space0};
+use nom::combinator::{eof, map_opt, verify};
+use nom::sequence::{delimited, terminated, tuple};
+
+/// A line that isn't an edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based, 0 when parsing a single line.
+    pub line: usize,
+    /// 1-based.
+    pub column: usize,
+    pub expected: &'static str,
+}
+
+impl ParseError {
+    pub(crate) fn on_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, ", self.line)?;
+        }
+        write!(f, "column {}: expected {}", self.column, self.expected)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A node name, which runs up to the next ` to ` or ` = ` and can't be blank.
+fn name<'a>(until: &'static str) -> impl FnMut(&'a str) -> Parsed<'a, &'a str> {
+    let mut name = verify(take_until1(until), |name: &str| !name.trim().is_empty());
+    expect("a name", move |input| {
+        let (input, name) = name(input)?;
+        Ok((input, name.trim()))
+    })
+}
+
+/// Parses an edge like `London to Dublin = 464` into its two nodes and its weight.
+///
+/// # Example
+/// ```rust
+/// use aoc_graph::{Graph, ParseError};
+///
+/// let error = Graph::<usize>::parse(&["A to B = 1", "A to C = far"]).unwrap_err();
+/// assert_eq!(error, ParseError { line: 2, column: 10, expected: "a weight" });
+/// assert_eq!(error.to_string(), "line 2, column 10: expected a weight");
+/// ```
+pub fn parse_edge<W: FromStr>(line: &str) -> Result<(&str, &str, W), ParseError> {
+    let weight = map_opt(not_line_ending, |w: &str| w.trim_end().parse().ok());
+    let mut edge = tuple((
+        terminated(name(" to "), expect("` to `", tag(" to "))),
+        terminated(name(" = "), expect("` = `", tag(" = "))),
+        terminated(expect("a weight", delimited(space0, weight, space0)), eof),
+    ));
+    match edge(line) {
+        Ok((_, edge)) => Ok(edge),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError {
+            line: 0,
+            column: line.len() - e.input.len() + 1,
+            expected: e.what,
+        }),
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers only"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_edge() {
+        assert_eq!(
+            parse_edge("London to Dublin = 464"),
+            Ok(("London", "Dublin", 464usize))
+        );
+        assert_eq!(
+            parse_edge("New York to Los Angeles = -3 "),
+            Ok(("New York", "Los Angeles", -3i32))
+        );
+        let error = |line| parse_edge::<usize>(line).unwrap_err();
+        assert_eq!(error("London to = 464").expected, "a name");
+        assert_eq!(error("London Dublin = 464").expected, "a name");
+        assert_eq!(error(" to Dublin = 464").column, 1);
+        assert_eq!(error("London to Dublin 464").column, 11);
+        assert_eq!(error("London to Dublin = far").column, 20);
+    }
+}
@@ -0,0 +1,32 @@
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[clap(author, version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solves one part of a puzzle.
+    Run(Run),
+    /// Lists every puzzle the runner knows how to solve.
+    List,
+}
+
+#[derive(Debug, Args)]
+pub struct Run {
+    #[clap(long)]
+    pub year: u16,
+
+    #[clap(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    #[clap(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    /// puzzle input. Defaults to the input embedded in the puzzle, if any, and to stdin
+    /// otherwise. Pass `-` to force stdin.
+    pub input: Option<String>,
+}
@@ -0,0 +1,34 @@
+use std::process;
+
+use clap::Parser;
+
+mod args;
+mod puzzles;
+
+use args::{Cli, Command};
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::List => {
+            for (year, day, name, _) in puzzles::PUZZLES {
+                println!("{year} {day:>2} {name}");
+            }
+        }
+        Command::Run(run) => {
+            let Some(solve) = puzzles::find(run.year, run.day) else {
+                eprintln!("Error: no solution for {} day {}", run.year, run.day);
+                process::exit(1);
+            };
+
+            match solve(run.part, run.input) {
+                Ok(answer) => println!("{answer}"),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+    }
+}
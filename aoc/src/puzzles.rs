@@ -0,0 +1,101 @@
+use aoc_common::{Answer, Input, Solution, Source};
+
+type Solver = fn(u8, Option<String>) -> Answer;
+
+/// Every registered puzzle, ordered by year and day.
+pub const PUZZLES: &[(u16, u8, &str, Solver)] = &[
+    (2015, 1, "floors", solve::<floors::Puzzle>),
+    (2015, 2, "gifts", solve::<gifts::Puzzle>),
+    (2015, 3, "houses", solve::<houses::Puzzle>),
+    (2015, 4, "adventcoins", solve::<adventcoins::Puzzle>),
+    (2015, 5, "nice", solve::<nice::Puzzle>),
+    (2015, 6, "lightgrid", solve::<lightgrid::Puzzle>),
+    (2015, 7, "circuits", solve::<circuits::Puzzle>),
+    (2015, 8, "matchsticks", solve::<matchsticks::Puzzle>),
+    (
+        2015,
+        9,
+        "travelling-santaman",
+        solve::<travelling_santaman::Puzzle>,
+    ),
+    (2015, 10, "look-and-say", solve::<look_and_say::Puzzle>),
+    (2015, 11, "next-pwd", solve::<next_pwd::Puzzle>),
+    (2015, 12, "abacus", solve::<abacus::Puzzle>),
+    (2015, 13, "dinner-table", solve::<dinner_table::Puzzle>),
+    (
+        2015,
+        14,
+        "reindeer-olympics",
+        solve::<reindeer_olympics::Puzzle>,
+    ),
+    (2015, 15, "recipe", solve::<recipe::Puzzle>),
+    (2015, 17, "eggnog", solve::<eggnog::Puzzle>),
+    (2015, 21, "rpg", solve::<rpg::Puzzle>),
+    (2015, 23, "turing", solve::<turing::Puzzle>),
+    (2015, 25, "cop", solve::<cop::Puzzle>),
+    (2016, 1, "blocks", solve::<blocks::Puzzle>),
+    (2016, 2, "keypad", solve::<keypad::Puzzle>),
+    (2016, 3, "triangles", solve::<triangles::Puzzle>),
+    (2016, 4, "rooms", solve::<rooms::Puzzle>),
+    (
+        2016,
+        5,
+        "password-cracker",
+        solve::<password_cracker::Puzzle>,
+    ),
+    (2016, 6, "ecc", solve::<ecc::Puzzle>),
+    (2016, 8, "tfa", solve::<tfa::Puzzle>),
+    (2016, 9, "expanse", solve::<expanse::Puzzle>),
+    (2016, 12, "assembunny", solve::<assembunny::Puzzle>),
+    (2016, 17, "shpath", solve::<shpath::Puzzle>),
+    (2016, 18, "automaton", solve::<automaton::Puzzle>),
+];
+
+pub fn find(year: u16, day: u8) -> Option<Solver> {
+    PUZZLES
+        .iter()
+        .find(|(y, d, _, _)| *y == year && *d == day)
+        .map(|(_, _, _, solver)| *solver)
+}
+
+/// An input handed over on the command line wins over the one embedded in the puzzle. Without
+/// either, the input is read from stdin.
+fn input<S: Solution>(arg: Option<String>) -> Input {
+    match (arg, S::INPUT) {
+        (None, Some(embedded)) => Input::embedded(embedded),
+        (arg, _) => Input::new(Source::from_arg(arg)),
+    }
+}
+
+fn solve<S: Solution>(part: u8, arg: Option<String>) -> Answer {
+    let input = input::<S>(arg);
+    match part {
+        1 => S::part_one(&input),
+        _ => S::part_two(&input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzles_are_sorted_and_unique() {
+        assert!(PUZZLES
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+    }
+
+    #[test]
+    fn test_embedded_input() {
+        let solve = find(2016, 1).unwrap();
+        assert_eq!(solve(1, None).unwrap(), "146");
+        assert!(find(2015, 16).is_none());
+    }
+
+    #[test]
+    fn test_unsolved_part() {
+        let solve = find(2015, 25).unwrap();
+        assert!(solve(2, None).is_err());
+    }
+}